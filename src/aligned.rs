@@ -0,0 +1,401 @@
+//! Heap buffers aligned for the `load_aligned` and `store_aligned` vector methods.
+
+use crate::{f32x8, f64x4, i16x16, i32x8, i64x4, i8x32, u16x16, u32x8, u64x4, u8x32};
+use std::{
+    alloc::{self, Layout},
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+    ops::{Deref, DerefMut},
+    ptr::{self, NonNull},
+    slice::{self, ChunksExact},
+};
+
+#[inline(always)]
+pub(crate) fn is_aligned<T>(a: *const T, align: usize) -> bool { (a as usize).is_multiple_of(align) }
+
+/// A growable buffer like `Vec<T>` whose memory is aligned to `ALIGN` bytes, 32 by default. `ALIGN` must be a power
+/// of two of at least 32, e.g. 64 to align to cache lines.
+pub struct AlignedVec<T, const ALIGN: usize = 32> {
+    ptr:     NonNull<T>,
+    len:     usize,
+    cap:     usize,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: Send, const ALIGN: usize> Send for AlignedVec<T, ALIGN> {}
+unsafe impl<T: Sync, const ALIGN: usize> Sync for AlignedVec<T, ALIGN> {}
+
+impl<T, const ALIGN: usize> AlignedVec<T, ALIGN> {
+    const VALID_ALIGN: () = assert!(ALIGN.is_power_of_two() && ALIGN >= 32, "ALIGN must be a power of two >= 32");
+
+    #[inline]
+    pub fn new() -> Self {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::VALID_ALIGN;
+        let cap = if mem::size_of::<T>() == 0 { usize::MAX } else { 0 };
+        Self {
+            ptr: dangling::<T, ALIGN>(),
+            len: 0,
+            cap,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut v = Self::new();
+        v.reserve(capacity);
+        v
+    }
+
+    /// `n` clones of `elem`, like `vec![elem; n]`.
+    pub fn from_elem(elem: T, n: usize) -> Self
+    where
+        T: Clone,
+    {
+        let mut v = Self::with_capacity(n);
+        v.resize(n, elem);
+        v
+    }
+
+    #[inline]
+    pub fn capacity(&self) -> usize { self.cap }
+
+    #[inline]
+    pub fn as_ptr(&self) -> *const T { self.ptr.as_ptr() }
+
+    #[inline]
+    pub fn as_mut_ptr(&mut self) -> *mut T { self.ptr.as_ptr() }
+
+    #[inline]
+    pub fn as_slice(&self) -> &[T] { self }
+
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [T] { self }
+
+    /// Reserves capacity for at least `additional` more elements.
+    pub fn reserve(&mut self, additional: usize) {
+        let required = self.len.checked_add(additional).expect("Capacity overflow!");
+
+        if required > self.cap {
+            self.realloc(required.max(self.cap * 2).max(4));
+        }
+    }
+
+    #[inline]
+    pub fn push(&mut self, value: T) {
+        if self.len == self.cap {
+            self.reserve(1);
+        }
+
+        unsafe { self.ptr.as_ptr().add(self.len).write(value) };
+        self.len += 1;
+    }
+
+    #[inline]
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+
+        self.len -= 1;
+        Some(unsafe { self.ptr.as_ptr().add(self.len).read() })
+    }
+
+    /// Drops the elements from `len` on, does nothing if `len` is not less than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            let tail = ptr::slice_from_raw_parts_mut(unsafe { self.ptr.as_ptr().add(len) }, self.len - len);
+            self.len = len;
+            unsafe { ptr::drop_in_place(tail) };
+        }
+    }
+
+    #[inline]
+    pub fn clear(&mut self) { self.truncate(0) }
+
+    /// Truncates or extends the buffer with clones of `value` to `new_len` elements.
+    pub fn resize(&mut self, new_len: usize, value: T)
+    where
+        T: Clone,
+    {
+        if new_len <= self.len {
+            return self.truncate(new_len);
+        }
+
+        self.reserve(new_len - self.len);
+
+        for _ in self.len + 1..new_len {
+            self.push(value.clone());
+        }
+
+        self.push(value);
+    }
+
+    pub fn extend_from_slice(&mut self, other: &[T])
+    where
+        T: Clone,
+    {
+        self.reserve(other.len());
+
+        for x in other {
+            self.push(x.clone());
+        }
+    }
+
+    /// Converts into an [`AlignedBox`], dropping any excess capacity.
+    pub fn into_boxed_slice(mut self) -> AlignedBox<[T], ALIGN> {
+        if mem::size_of::<T>() != 0 && self.cap != self.len {
+            if self.len == 0 {
+                unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Self::layout(self.cap)) };
+                self.ptr = dangling::<T, ALIGN>();
+                self.cap = 0;
+            } else {
+                self.realloc(self.len);
+            }
+        }
+
+        let b = AlignedBox {
+            ptr:     NonNull::slice_from_raw_parts(self.ptr, self.len),
+            _marker: PhantomData,
+        };
+        mem::forget(self);
+        b
+    }
+
+    fn realloc(&mut self, cap: usize) {
+        let layout = Self::layout(cap);
+
+        let ptr = unsafe {
+            if self.cap == 0 {
+                alloc::alloc(layout)
+            } else {
+                alloc::realloc(self.ptr.as_ptr() as *mut u8, Self::layout(self.cap), layout.size())
+            }
+        };
+
+        self.ptr = NonNull::new(ptr as *mut T).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        self.cap = cap;
+    }
+
+    fn layout(cap: usize) -> Layout {
+        mem::size_of::<T>()
+            .checked_mul(cap)
+            .and_then(|size| Layout::from_size_align(size, ALIGN.max(mem::align_of::<T>())).ok())
+            .expect("Capacity overflow!")
+    }
+}
+
+impl<T, const ALIGN: usize> Drop for AlignedVec<T, ALIGN> {
+    fn drop(&mut self) {
+        self.clear();
+
+        if mem::size_of::<T>() != 0 && self.cap != 0 {
+            unsafe { alloc::dealloc(self.ptr.as_ptr() as *mut u8, Self::layout(self.cap)) };
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> Deref for AlignedVec<T, ALIGN> {
+    type Target = [T];
+
+    #[inline]
+    fn deref(&self) -> &[T] { unsafe { slice::from_raw_parts(self.ptr.as_ptr(), self.len) } }
+}
+
+impl<T, const ALIGN: usize> DerefMut for AlignedVec<T, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut [T] { unsafe { slice::from_raw_parts_mut(self.ptr.as_ptr(), self.len) } }
+}
+
+impl<T, const ALIGN: usize> Default for AlignedVec<T, ALIGN> {
+    fn default() -> Self { Self::new() }
+}
+
+impl<T: Clone, const ALIGN: usize> Clone for AlignedVec<T, ALIGN> {
+    fn clone(&self) -> Self { Self::from(&**self) }
+}
+
+impl<T: fmt::Debug, const ALIGN: usize> fmt::Debug for AlignedVec<T, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+impl<T: PartialEq, const ALIGN: usize> PartialEq for AlignedVec<T, ALIGN> {
+    fn eq(&self, other: &Self) -> bool { **self == **other }
+}
+
+impl<T: Clone, const ALIGN: usize> From<&[T]> for AlignedVec<T, ALIGN> {
+    fn from(a: &[T]) -> Self {
+        let mut v = Self::new();
+        v.extend_from_slice(a);
+        v
+    }
+}
+
+impl<T, const ALIGN: usize> Extend<T> for AlignedVec<T, ALIGN> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        self.reserve(iter.size_hint().0);
+
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl<T, const ALIGN: usize> FromIterator<T> for AlignedVec<T, ALIGN> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut v = Self::new();
+        v.extend(iter);
+        v
+    }
+}
+
+/// A fixed size heap allocation like `Box<[T]>` whose memory is aligned to `ALIGN` bytes, 32 by default. Created from
+/// an [`AlignedVec`] or a slice.
+pub struct AlignedBox<T: ?Sized, const ALIGN: usize = 32> {
+    ptr:     NonNull<T>,
+    _marker: PhantomData<T>,
+}
+
+unsafe impl<T: ?Sized + Send, const ALIGN: usize> Send for AlignedBox<T, ALIGN> {}
+unsafe impl<T: ?Sized + Sync, const ALIGN: usize> Sync for AlignedBox<T, ALIGN> {}
+
+impl<T, const ALIGN: usize> AlignedBox<[T], ALIGN> {
+    /// Converts back into an [`AlignedVec`] without reallocating.
+    pub fn into_vec(self) -> AlignedVec<T, ALIGN> {
+        let len = self.len();
+        let cap = if mem::size_of::<T>() == 0 { usize::MAX } else { len };
+        let v = AlignedVec {
+            ptr: self.ptr.cast(),
+            len,
+            cap,
+            _marker: PhantomData,
+        };
+        mem::forget(self);
+        v
+    }
+}
+
+impl<T: ?Sized, const ALIGN: usize> Drop for AlignedBox<T, ALIGN> {
+    fn drop(&mut self) {
+        unsafe {
+            let layout = Layout::for_value(self.ptr.as_ref());
+            ptr::drop_in_place(self.ptr.as_ptr());
+
+            if layout.size() != 0 {
+                alloc::dealloc(self.ptr.as_ptr() as *mut u8, layout.align_to(ALIGN).unwrap());
+            }
+        }
+    }
+}
+
+impl<T: ?Sized, const ALIGN: usize> Deref for AlignedBox<T, ALIGN> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { unsafe { self.ptr.as_ref() } }
+}
+
+impl<T: ?Sized, const ALIGN: usize> DerefMut for AlignedBox<T, ALIGN> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T { unsafe { self.ptr.as_mut() } }
+}
+
+impl<T: Clone, const ALIGN: usize> Clone for AlignedBox<[T], ALIGN> {
+    fn clone(&self) -> Self { Self::from(&**self) }
+}
+
+impl<T: ?Sized + fmt::Debug, const ALIGN: usize> fmt::Debug for AlignedBox<T, ALIGN> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt::Debug::fmt(&**self, f) }
+}
+
+impl<T: ?Sized + PartialEq, const ALIGN: usize> PartialEq for AlignedBox<T, ALIGN> {
+    fn eq(&self, other: &Self) -> bool { **self == **other }
+}
+
+impl<T: Clone, const ALIGN: usize> From<&[T]> for AlignedBox<[T], ALIGN> {
+    fn from(a: &[T]) -> Self { AlignedVec::from(a).into_boxed_slice() }
+}
+
+impl<T, const ALIGN: usize> From<AlignedVec<T, ALIGN>> for AlignedBox<[T], ALIGN> {
+    fn from(v: AlignedVec<T, ALIGN>) -> Self { v.into_boxed_slice() }
+}
+
+impl<T, const ALIGN: usize> FromIterator<T> for AlignedBox<[T], ALIGN> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self { AlignedVec::from_iter(iter).into_boxed_slice() }
+}
+
+/// A non-null pointer aligned to `ALIGN` bytes for empty buffers.
+#[inline(always)]
+fn dangling<T, const ALIGN: usize>() -> NonNull<T> {
+    unsafe { NonNull::new_unchecked(ptr::without_provenance_mut(ALIGN.max(mem::align_of::<T>()))) }
+}
+
+/// Iterator over the aligned 256-bit vectors of an [`AlignedVec`] or [`AlignedBox`], see
+/// [`AlignedVec::chunks_simd`].
+#[derive(Clone, Debug)]
+pub struct ChunksSimd<'a, T, V> {
+    chunks:  ChunksExact<'a, T>,
+    _marker: PhantomData<V>,
+}
+
+impl<'a, T, V> ChunksSimd<'a, T, V> {
+    #[inline]
+    fn new(a: &'a [T]) -> Self {
+        Self {
+            chunks:  a.chunks_exact(32 / mem::size_of::<T>()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// The elements after the last full vector.
+    #[inline]
+    pub fn remainder(&self) -> &'a [T] { self.chunks.remainder() }
+}
+
+macro_rules! chunks_simd {
+    ($($t:ty => $v:ident),*) => {$(
+        impl<const ALIGN: usize> AlignedVec<$t, ALIGN> {
+            /// Iterates over the buffer as aligned vectors, the elements after the last full vector are in
+            /// [`ChunksSimd::remainder`].
+            #[inline]
+            pub fn chunks_simd(&self) -> ChunksSimd<'_, $t, $v> { ChunksSimd::new(self) }
+        }
+
+        impl<const ALIGN: usize> AlignedBox<[$t], ALIGN> {
+            /// Iterates over the buffer as aligned vectors, the elements after the last full vector are in
+            /// [`ChunksSimd::remainder`].
+            #[inline]
+            pub fn chunks_simd(&self) -> ChunksSimd<'_, $t, $v> { ChunksSimd::new(self) }
+        }
+
+        impl Iterator for ChunksSimd<'_, $t, $v> {
+            type Item = $v;
+
+            // Chunks start at multiples of 32 bytes from a buffer aligned to at least 32 bytes
+            #[inline]
+            fn next(&mut self) -> Option<$v> { self.chunks.next().map(|c| unsafe { $v::from_ptr_aligned(c.as_ptr()) }) }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { self.chunks.size_hint() }
+        }
+
+        impl DoubleEndedIterator for ChunksSimd<'_, $t, $v> {
+            #[inline]
+            fn next_back(&mut self) -> Option<$v> {
+                self.chunks.next_back().map(|c| unsafe { $v::from_ptr_aligned(c.as_ptr()) })
+            }
+        }
+
+        impl ExactSizeIterator for ChunksSimd<'_, $t, $v> {}
+        impl FusedIterator for ChunksSimd<'_, $t, $v> {}
+    )*};
+}
+
+chunks_simd!(
+    f32 => f32x8, f64 => f64x4, i8 => i8x32, i16 => i16x16, i32 => i32x8, i64 => i64x4, u8 => u8x32,
+    u16 => u16x16, u32 => u32x8, u64 => u64x4
+);
@@ -0,0 +1,7 @@
+#[cfg(all(target_arch = "x86", not(feature = "scalar")))]
+pub use std::arch::x86::*;
+#[cfg(all(target_arch = "x86_64", not(feature = "scalar")))]
+pub use std::arch::x86_64::*;
+
+#[cfg(any(feature = "scalar", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+pub use crate::scalar::*;
@@ -0,0 +1,109 @@
+//! Native AVX-512F versions of the 512-bit operations that are expensive to emulate with two 256-bit halves. Only
+//! called after `has_avx512f` returned true.
+
+use crate::{arch::*, f32x16, f32x8, f64x4, i32x16, i32x8, i64x4, i64x8};
+use std::mem::transmute;
+
+#[inline(always)]
+fn ps(a: f32x16) -> __m512 { unsafe { transmute::<[__m256; 2], __m512>([a.lo().into(), a.hi().into()]) } }
+
+#[inline(always)]
+fn from_ps(a: __m512) -> f32x16 {
+    let [lo, hi] = unsafe { transmute::<__m512, [__m256; 2]>(a) };
+    f32x16::concat(lo.into(), hi.into())
+}
+
+#[inline(always)]
+fn epi32(a: i32x16) -> __m512i { unsafe { transmute::<[__m256i; 2], __m512i>([a.lo().into(), a.hi().into()]) } }
+
+#[inline(always)]
+fn from_epi32(a: __m512i) -> i32x16 {
+    let [lo, hi] = unsafe { transmute::<__m512i, [__m256i; 2]>(a) };
+    i32x16::concat(lo.into(), hi.into())
+}
+
+#[inline(always)]
+fn epi64(a: i64x8) -> __m512i { unsafe { transmute::<[__m256i; 2], __m512i>([a.lo().into(), a.hi().into()]) } }
+
+#[inline(always)]
+fn from_epi64(a: __m512i) -> i64x8 {
+    let [lo, hi] = unsafe { transmute::<__m512i, [__m256i; 2]>(a) };
+    i64x8::concat(lo.into(), hi.into())
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn permutexvar_ps(a: f32x16, idx: i32x16) -> f32x16 {
+    from_ps(_mm512_permutexvar_ps(epi32(idx), ps(a)))
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn i32gather_ps<const SCALE: i32>(a: *const f32, idx: i32x16) -> f32x16 {
+    from_ps(_mm512_i32gather_ps::<SCALE>(epi32(idx), a))
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn i32gather_epi32<const SCALE: i32>(a: *const i32, idx: i32x16) -> i32x16 {
+    from_epi32(_mm512_i32gather_epi32::<SCALE>(epi32(idx), a))
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn i64gather_epi64<const SCALE: i32>(a: *const i64, idx: i64x8) -> i64x8 {
+    from_epi64(_mm512_i64gather_epi64::<SCALE>(epi64(idx), a))
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i32scatter_ps<const SCALE: i32>(a: *mut f32, mask: u16, idx: i32x16, v: f32x16) {
+    _mm512_mask_i32scatter_ps::<SCALE>(a, mask, epi32(idx), ps(v))
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i32scatter_epi32<const SCALE: i32>(a: *mut i32, mask: u16, idx: i32x16, v: i32x16) {
+    _mm512_mask_i32scatter_epi32::<SCALE>(a, mask, epi32(idx), epi32(v))
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i64scatter_epi64<const SCALE: i32>(a: *mut i64, mask: u8, idx: i64x8, v: i64x8) {
+    _mm512_mask_i64scatter_epi64::<SCALE>(a, mask, epi64(idx), epi64(v))
+}
+
+// The 256-bit scatters use the lower half of the 512-bit instructions, as AVX-512F alone has no 256-bit scatter
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i32scatter_ps_256<const SCALE: i32>(a: *mut f32, mask: u8, idx: i32x8, v: f32x8) {
+    _mm512_mask_i32scatter_ps::<SCALE>(
+        a,
+        mask as u16,
+        _mm512_castsi256_si512(idx.into()),
+        _mm512_castps256_ps512(v.into()),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i32scatter_epi32_256<const SCALE: i32>(a: *mut i32, mask: u8, idx: i32x8, v: i32x8) {
+    _mm512_mask_i32scatter_epi32::<SCALE>(
+        a,
+        mask as u16,
+        _mm512_castsi256_si512(idx.into()),
+        _mm512_castsi256_si512(v.into()),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i64scatter_epi64_256<const SCALE: i32>(a: *mut i64, mask: u8, idx: i64x4, v: i64x4) {
+    _mm512_mask_i64scatter_epi64::<SCALE>(
+        a,
+        mask & 0xf,
+        _mm512_castsi256_si512(idx.into()),
+        _mm512_castsi256_si512(v.into()),
+    )
+}
+
+#[target_feature(enable = "avx512f")]
+pub(crate) unsafe fn mask_i64scatter_pd_256<const SCALE: i32>(a: *mut f64, mask: u8, idx: i64x4, v: f64x4) {
+    _mm512_mask_i64scatter_pd::<SCALE>(
+        a,
+        mask & 0xf,
+        _mm512_castsi256_si512(idx.into()),
+        _mm512_castpd256_pd512(v.into()),
+    )
+}
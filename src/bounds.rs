@@ -0,0 +1,16 @@
+use std::{error::Error, fmt};
+
+/// Error of the `try_gather` and `try_scatter` methods when an index is outside the slice.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct OutOfBounds {
+    /// Bitmask of the lanes with an out of bounds index.
+    pub lanes: u32,
+}
+
+impl fmt::Display for OutOfBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "index out of bounds in lanes {:#b}", self.lanes)
+    }
+}
+
+impl Error for OutOfBounds {}
@@ -0,0 +1,139 @@
+/// CPU features relevant to the vector types, as detected at runtime. Only [`detect`] creates one, so the features
+/// it reports are guaranteed to be present.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Capabilities {
+    sse41:   bool,
+    avx:     bool,
+    avx2:    bool,
+    fma:     bool,
+    avx512f: bool,
+}
+
+impl Capabilities {
+    #[inline]
+    pub fn has_sse41(self) -> bool { self.sse41 }
+
+    #[inline]
+    pub fn has_avx(self) -> bool { self.avx }
+
+    #[inline]
+    pub fn has_avx2(self) -> bool { self.avx2 }
+
+    #[inline]
+    pub fn has_fma(self) -> bool { self.fma }
+
+    #[inline]
+    pub fn has_avx512f(self) -> bool { self.avx512f }
+
+    /// Returns a token proving AVX2 and FMA are available, which all vector types require.
+    #[inline]
+    pub fn avx2(self) -> Option<Avx2> {
+        if self.avx && self.avx2 && self.fma {
+            Some(Avx2 { _private: () })
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline]
+pub fn detect() -> Capabilities {
+    Capabilities {
+        sse41:   is_x86_feature_detected!("sse4.1"),
+        avx:     is_x86_feature_detected!("avx"),
+        avx2:    is_x86_feature_detected!("avx2"),
+        fma:     is_x86_feature_detected!("fma"),
+        avx512f: is_x86_feature_detected!("avx512f"),
+    }
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline]
+pub fn detect() -> Capabilities { Capabilities::default() }
+
+/// Zero sized proof that the CPU supports AVX2 and FMA.
+#[derive(Debug, Copy, Clone)]
+pub struct Avx2 {
+    _private: (),
+}
+
+impl Avx2 {
+    #[inline]
+    pub fn new() -> Option<Self> {
+        if has_avx2_fma() {
+            Some(Self { _private: () })
+        } else {
+            None
+        }
+    }
+
+    /// # Safety
+    /// The CPU must support AVX, AVX2 and FMA.
+    #[inline]
+    pub unsafe fn new_unchecked() -> Self { Self { _private: () } }
+
+    /// Runs `f` compiled with AVX2 and FMA enabled, so the inlined vector operations use VEX encoded instructions.
+    #[inline]
+    pub fn run<R, F: FnOnce() -> R>(self, f: F) -> R {
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        #[target_feature(enable = "avx,avx2,fma")]
+        unsafe fn run_avx2<R, F: FnOnce() -> R>(f: F) -> R { f() }
+
+        #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+        unsafe {
+            run_avx2(f)
+        }
+
+        #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+        f()
+    }
+}
+
+/// Runs `simd` under an [`Avx2`] token if the CPU supports it, otherwise runs `fallback`.
+#[inline]
+pub fn dispatch<R>(simd: impl FnOnce(Avx2) -> R, fallback: impl FnOnce() -> R) -> R {
+    match Avx2::new() {
+        Some(token) => token.run(|| simd(token)),
+        None => fallback(),
+    }
+}
+
+/// Panics unless AVX2 and FMA are available, a no-op with the scalar backend. Called by every function creating a
+/// vector from scalars or memory, so holding a vector value proves the intrinsics behind its methods are safe to
+/// execute.
+#[inline(always)]
+pub(crate) fn assert_supported() {
+    #[cfg(not(any(
+        feature = "scalar",
+        not(any(target_arch = "x86", target_arch = "x86_64")),
+        all(target_feature = "avx2", target_feature = "fma")
+    )))]
+    if !has_avx2_fma() {
+        unsupported()
+    }
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+fn has_avx2_fma() -> bool {
+    is_x86_feature_detected!("avx") && is_x86_feature_detected!("avx2") && is_x86_feature_detected!("fma")
+}
+
+#[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline(always)]
+fn has_avx2_fma() -> bool { false }
+
+#[cfg(not(any(
+    feature = "scalar",
+    not(any(target_arch = "x86", target_arch = "x86_64")),
+    all(target_feature = "avx2", target_feature = "fma")
+)))]
+#[cold]
+#[inline(never)]
+fn unsupported() -> ! { panic!("AVX2 and FMA not supported!") }
+
+/// Whether the 512-bit types can use native AVX-512F instructions instead of two 256-bit halves.
+#[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline(always)]
+pub(crate) fn has_avx512f() -> bool { is_x86_feature_detected!("avx512f") }
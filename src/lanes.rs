@@ -0,0 +1,16 @@
+use std::fmt;
+
+/// Formats lanes as `(v1, v2, ...)`, passing the formatter options on to each lane.
+pub(crate) fn fmt_lanes<T: fmt::Display>(lanes: &[T], f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str("(")?;
+
+    for (i, v) in lanes.iter().enumerate() {
+        if i > 0 {
+            f.write_str(", ")?;
+        }
+
+        fmt::Display::fmt(v, f)?;
+    }
+
+    f.write_str(")")
+}
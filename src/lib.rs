@@ -1,14 +1,71 @@
-#![allow(clippy::too_many_arguments)]
+#![allow(clippy::too_many_arguments, clippy::missing_safety_doc)]
 
+pub mod activation;
+mod aligned;
+mod arch;
+#[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+mod avx512;
+mod bounds;
+mod detect;
+mod lanes;
+pub mod math;
+mod partial;
+mod reduce;
+mod scatter;
 mod simd_f32;
 mod simd_f64;
 mod simd_i16;
 mod simd_i32;
 mod simd_i64;
+mod simd_i8;
+mod simd_mask;
+mod simd_u16;
+mod simd_u32;
+mod simd_u64;
+mod simd_u8;
+pub mod stream;
+mod swizzle;
+mod traits;
 
+#[cfg(any(feature = "scalar", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+mod scalar;
+
+pub use aligned::{AlignedBox, AlignedVec, ChunksSimd};
+pub use bounds::OutOfBounds;
+pub use detect::{detect, dispatch, Avx2, Capabilities};
+pub use simd_f32::f32x16;
+pub use simd_f32::f32x4;
 pub use simd_f32::f32x8;
+pub use simd_f64::f64x2;
 pub use simd_f64::f64x4;
+pub use simd_f64::f64x8;
 pub use simd_i16::i16x16;
+pub use simd_i16::i16x8;
+pub use simd_i32::i32x16;
 pub use simd_i32::i32x4;
 pub use simd_i32::i32x8;
+pub use simd_i64::i64x2;
 pub use simd_i64::i64x4;
+pub use simd_i64::i64x8;
+pub use simd_i8::i8x32;
+pub use simd_mask::m16x16;
+pub use simd_mask::m16x8;
+pub use simd_mask::m32x16;
+pub use simd_mask::m32x4;
+pub use simd_mask::m32x8;
+pub use simd_mask::m64x2;
+pub use simd_mask::m64x4;
+pub use simd_mask::m64x8;
+pub use simd_mask::m8x32;
+pub use simd_u16::u16x16;
+pub use simd_u16::u16x8;
+pub use simd_u32::u32x4;
+pub use simd_u32::u32x8;
+pub use simd_u64::u64x2;
+pub use simd_u64::u64x4;
+pub use simd_u8::u8x32;
+pub use swizzle::Swizzle;
+pub use traits::{SimdFloat, SimdInt, SimdVector};
+
+#[cfg(any(feature = "scalar", not(any(target_arch = "x86", target_arch = "x86_64"))))]
+pub use scalar::{__m128, __m128d, __m128i, __m256, __m256d, __m256i};
@@ -0,0 +1,24 @@
+//! Lane masks for the `load_partial` and `store_partial` vector methods.
+
+use crate::arch::*;
+
+/// Sets the 32-bit lanes below `len`, as the mask of a masked load or store of `len` elements.
+#[inline(always)]
+pub(crate) unsafe fn mask_epi32x4(len: usize) -> __m128i {
+    _mm_cmpgt_epi32(_mm_set1_epi32(len as i32), _mm_set_epi32(3, 2, 1, 0))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn mask_epi64x2(len: usize) -> __m128i {
+    _mm_cmpgt_epi64(_mm_set1_epi64x(len as i64), _mm_set_epi64x(1, 0))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn mask_epi32x8(len: usize) -> __m256i {
+    _mm256_cmpgt_epi32(_mm256_set1_epi32(len as i32), _mm256_set_epi32(7, 6, 5, 4, 3, 2, 1, 0))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn mask_epi64x4(len: usize) -> __m256i {
+    _mm256_cmpgt_epi64(_mm256_set1_epi64x(len as i64), _mm256_set_epi64x(3, 2, 1, 0))
+}
@@ -0,0 +1,7 @@
+#[inline]
+pub(crate) fn first_lane(bitmask: u32) -> usize {
+    match bitmask {
+        0 => 0,
+        bits => bits.trailing_zeros() as usize,
+    }
+}
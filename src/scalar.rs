@@ -0,0 +1,1966 @@
+//! Portable emulation of the x86 intrinsics used by the vector types, selected by the `scalar` feature or on non-x86
+//! targets. Every function follows the Intel definition of the intrinsic with the same name, including the lane order
+//! within 128-bit halves, so both backends produce bit identical results. The exception are the `rcp` and `rsqrt`
+//! estimates, which are exact here instead of the hardware's 12-bit approximation, so `rcp`, `rsqrt` and their `_nr`
+//! refinements can differ in the last bits between the backends.
+
+#![allow(non_camel_case_types)]
+
+use std::array::from_fn;
+use std::mem::{size_of, transmute_copy};
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(16))]
+pub struct __m128([f32; 4]);
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(16))]
+pub struct __m128d([f64; 2]);
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(16))]
+pub struct __m128i([i64; 2]);
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(32))]
+pub struct __m256([f32; 8]);
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(32))]
+pub struct __m256d([f64; 4]);
+
+#[derive(Debug, Copy, Clone)]
+#[repr(C, align(32))]
+pub struct __m256i([i64; 4]);
+
+pub const _CMP_EQ_OQ: i32 = 0x00;
+pub const _CMP_UNORD_Q: i32 = 0x03;
+pub const _CMP_NEQ_UQ: i32 = 0x04;
+pub const _CMP_NEQ_OQ: i32 = 0x0c;
+pub const _CMP_GE_OQ: i32 = 0x1d;
+pub const _CMP_GT_OQ: i32 = 0x1e;
+pub const _CMP_LE_OQ: i32 = 0x12;
+pub const _CMP_LT_OQ: i32 = 0x11;
+
+pub const _MM_HINT_T0: i32 = 3;
+pub const _MM_HINT_T1: i32 = 2;
+pub const _MM_HINT_T2: i32 = 1;
+pub const _MM_HINT_NTA: i32 = 0;
+
+pub const _MM_FROUND_TO_NEAREST_INT: i32 = 0x00;
+pub const _MM_FROUND_TO_NEG_INF: i32 = 0x01;
+pub const _MM_FROUND_TO_POS_INF: i32 = 0x02;
+pub const _MM_FROUND_TO_ZERO: i32 = 0x03;
+pub const _MM_FROUND_CUR_DIRECTION: i32 = 0x04;
+pub const _MM_FROUND_NO_EXC: i32 = 0x08;
+
+#[inline(always)]
+fn cast<A: Copy, B: Copy>(a: A) -> B {
+    assert_eq!(size_of::<A>(), size_of::<B>());
+    unsafe { transmute_copy(&a) }
+}
+
+#[inline(always)]
+fn zip<V: Copy, T: Copy, const N: usize>(a: V, b: V, f: impl Fn(T, T) -> T) -> V {
+    let a: [T; N] = cast(a);
+    let b: [T; N] = cast(b);
+    cast(from_fn::<T, N, _>(|i| f(a[i], b[i])))
+}
+
+#[inline(always)]
+fn lanes<V: Copy, T: Copy, const N: usize>(a: V) -> [T; N] { cast(a) }
+
+#[inline(always)]
+fn blend<V: Copy, T: Copy, const N: usize>(a: V, b: V, mask: impl Fn(usize) -> bool) -> V {
+    let a: [T; N] = cast(a);
+    let b: [T; N] = cast(b);
+    cast(from_fn::<T, N, _>(|i| if mask(i) { b[i] } else { a[i] }))
+}
+
+#[inline(always)]
+fn movemask<T: Copy, const N: usize>(a: [T; N], negative: impl Fn(T) -> bool) -> i32 {
+    a.iter().enumerate().fold(0, |m, (i, v)| m | (negative(*v) as i32) << i)
+}
+
+#[inline(always)]
+fn min<T: PartialOrd>(a: T, b: T) -> T {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+#[inline(always)]
+fn max<T: PartialOrd>(a: T, b: T) -> T {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+// The rcp and rsqrt estimates treat subnormal inputs as 0 and flush subnormal results to 0
+#[inline(always)]
+fn flush(a: f32) -> f32 {
+    if a.is_subnormal() {
+        0.0f32.copysign(a)
+    } else {
+        a
+    }
+}
+
+#[inline(always)]
+fn mask<T: Copy + Default + std::ops::Not<Output = T>>(v: bool) -> T {
+    if v {
+        !T::default()
+    } else {
+        T::default()
+    }
+}
+
+#[inline(always)]
+fn compare<T: PartialOrd>(a: T, b: T, imm: i32) -> bool {
+    let unord = a.partial_cmp(&b).is_none();
+
+    match imm & 0xf {
+        0x0 => a == b,
+        0x1 => a < b,
+        0x2 => a <= b,
+        0x3 => unord,
+        0x4 => a != b,
+        0x5 => unord || a >= b,
+        0x6 => unord || a > b,
+        0x7 => !unord,
+        0x8 => a == b || unord,
+        0x9 => unord || a < b,
+        0xa => unord || a <= b,
+        0xb => false,
+        0xc => a != b && !unord,
+        0xd => a >= b,
+        0xe => a > b,
+        _ => true,
+    }
+}
+
+#[inline(always)]
+fn shift_count(count: __m128i) -> u32 { (count.0[0] as u64).min(u32::MAX as u64) as u32 }
+
+#[inline(always)]
+fn saturate_i8(v: i16) -> i8 { v.clamp(i8::MIN as i16, i8::MAX as i16) as i8 }
+
+#[inline(always)]
+fn saturate_i16(v: i32) -> i16 { v.clamp(i16::MIN as i32, i16::MAX as i32) as i16 }
+
+#[inline(always)]
+fn round(v: f64, rounding: i32) -> f64 {
+    // Rounding with the MXCSR direction uses its default, to nearest
+    match if rounding & _MM_FROUND_CUR_DIRECTION != 0 { _MM_FROUND_TO_NEAREST_INT } else { rounding & 3 } {
+        _MM_FROUND_TO_NEAREST_INT => v.round_ties_even(),
+        _MM_FROUND_TO_NEG_INF => v.floor(),
+        _MM_FROUND_TO_POS_INF => v.ceil(),
+        _ => v.trunc(),
+    }
+}
+
+#[inline(always)]
+fn cvt_f64_i32(v: f64) -> i32 {
+    let v = v.round_ties_even();
+    if (-2147483648.0..2147483648.0).contains(&v) {
+        v as i32
+    } else {
+        i32::MIN
+    }
+}
+
+#[inline(always)]
+fn cvtt_f64_i32(v: f64) -> i32 {
+    if v > -2147483649.0 && v < 2147483648.0 {
+        v as i32
+    } else {
+        i32::MIN
+    }
+}
+
+#[inline(always)]
+fn cvtt_f32_i32(v: f32) -> i32 {
+    if v > -2147483904.0 && v < 2147483648.0 {
+        v as i32
+    } else {
+        i32::MIN
+    }
+}
+
+#[inline(always)]
+unsafe fn gather<T: Copy, I: Into<i64>, const N: usize>(base: *const T, idx: [I; N], scale: i32) -> [T; N] {
+    let base = base as *const u8;
+    idx.map(|i| (base.offset((i.into() * scale as i64) as isize) as *const T).read_unaligned())
+}
+
+#[inline(always)]
+unsafe fn mask_gather<T: Copy, I: Into<i64>, M: Into<i64>, const N: usize>(
+    src: [T; N],
+    base: *const T,
+    idx: [I; N],
+    mask: [M; N],
+    scale: i32,
+) -> [T; N] {
+    let base = base as *const u8;
+    let mut r = src;
+
+    for (i, (idx, mask)) in idx.into_iter().zip(mask).enumerate() {
+        if mask.into() < 0 {
+            r[i] = (base.offset((idx.into() * scale as i64) as isize) as *const T).read_unaligned();
+        }
+    }
+
+    r
+}
+
+#[inline(always)]
+unsafe fn maskload<T: Copy + Default, M: Copy + Into<i64>, const N: usize>(mem_addr: *const T, mask: [M; N]) -> [T; N] {
+    from_fn(|i| if mask[i].into() < 0 { mem_addr.add(i).read_unaligned() } else { T::default() })
+}
+
+#[inline(always)]
+unsafe fn maskstore<T: Copy, M: Copy + Into<i64>, const N: usize>(mem_addr: *mut T, mask: [M; N], a: [T; N]) {
+    for (i, (m, v)) in mask.into_iter().zip(a).enumerate() {
+        if m.into() < 0 {
+            mem_addr.add(i).write_unaligned(v)
+        }
+    }
+}
+
+// Memory ordering and cache hints
+
+#[inline]
+pub unsafe fn _mm_sfence() { std::sync::atomic::fence(std::sync::atomic::Ordering::SeqCst) }
+
+#[inline]
+pub unsafe fn _mm_prefetch<const STRATEGY: i32>(_p: *const i8) {}
+
+// 128-bit float
+
+#[inline]
+pub unsafe fn _mm_add_ps(a: __m128, b: __m128) -> __m128 { zip::<_, f32, 4>(a, b, |a, b| a + b) }
+
+#[inline]
+pub unsafe fn _mm_add_ss(a: __m128, b: __m128) -> __m128 {
+    let mut r = a;
+    r.0[0] = a.0[0] + b.0[0];
+    r
+}
+
+#[inline]
+pub unsafe fn _mm_movehl_ps(a: __m128, b: __m128) -> __m128 { __m128([b.0[2], b.0[3], a.0[2], a.0[3]]) }
+
+#[inline]
+pub unsafe fn _mm_shuffle_ps<const MASK: i32>(a: __m128, b: __m128) -> __m128 {
+    let s = |i: i32| ((MASK >> (i * 2)) & 3) as usize;
+    __m128([a.0[s(0)], a.0[s(1)], b.0[s(2)], b.0[s(3)]])
+}
+
+#[inline]
+pub unsafe fn _mm_cvtss_f32(a: __m128) -> f32 { a.0[0] }
+
+#[inline]
+pub unsafe fn _mm_set_ps(a: f32, b: f32, c: f32, d: f32) -> __m128 { __m128([d, c, b, a]) }
+
+#[inline]
+pub unsafe fn _mm_set1_ps(a: f32) -> __m128 { __m128([a; 4]) }
+
+#[inline]
+pub unsafe fn _mm_loadu_ps(mem_addr: *const f32) -> __m128 { (mem_addr as *const __m128).read_unaligned() }
+
+#[inline]
+pub unsafe fn _mm_storeu_ps(mem_addr: *mut f32, a: __m128) { (mem_addr as *mut __m128).write_unaligned(a) }
+
+#[inline]
+pub unsafe fn _mm_load_ps(mem_addr: *const f32) -> __m128 { (mem_addr as *const __m128).read() }
+
+#[inline]
+pub unsafe fn _mm_store_ps(mem_addr: *mut f32, a: __m128) { (mem_addr as *mut __m128).write(a) }
+
+#[inline]
+pub unsafe fn _mm_stream_ps(mem_addr: *mut f32, a: __m128) { _mm_store_ps(mem_addr, a) }
+
+#[inline]
+pub unsafe fn _mm_maskload_ps(mem_addr: *const f32, mask: __m128i) -> __m128 {
+    cast(maskload(mem_addr, lanes::<_, i32, 4>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm_maskstore_ps(mem_addr: *mut f32, mask: __m128i, a: __m128) {
+    maskstore(mem_addr, lanes::<_, i32, 4>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm_castps_si128(a: __m128) -> __m128i { cast(a) }
+
+#[inline]
+pub unsafe fn _mm_castps_pd(a: __m128) -> __m128d { cast(a) }
+
+#[inline]
+pub unsafe fn _mm_sub_ps(a: __m128, b: __m128) -> __m128 { zip::<_, f32, 4>(a, b, |a, b| a - b) }
+
+#[inline]
+pub unsafe fn _mm_mul_ps(a: __m128, b: __m128) -> __m128 { zip::<_, f32, 4>(a, b, |a, b| a * b) }
+
+#[inline]
+pub unsafe fn _mm_div_ps(a: __m128, b: __m128) -> __m128 { zip::<_, f32, 4>(a, b, |a, b| a / b) }
+
+#[inline]
+pub unsafe fn _mm_sqrt_ps(a: __m128) -> __m128 { __m128(a.0.map(f32::sqrt)) }
+
+#[inline]
+pub unsafe fn _mm_fmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    __m128(from_fn(|i| a.0[i].mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_floor_ps(a: __m128) -> __m128 { __m128(a.0.map(f32::floor)) }
+
+#[inline]
+pub unsafe fn _mm_ceil_ps(a: __m128) -> __m128 { __m128(a.0.map(f32::ceil)) }
+
+#[inline]
+pub unsafe fn _mm_andnot_ps(a: __m128, b: __m128) -> __m128 { zip::<_, u32, 4>(a, b, |a, b| !a & b) }
+
+#[inline]
+pub unsafe fn _mm_xor_ps(a: __m128, b: __m128) -> __m128 { zip::<_, u32, 4>(a, b, |a, b| a ^ b) }
+
+#[inline]
+pub unsafe fn _mm_min_ps(a: __m128, b: __m128) -> __m128 { zip::<_, f32, 4>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_ps(a: __m128, b: __m128) -> __m128 { zip::<_, f32, 4>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_cmp_ps<const IMM5: i32>(a: __m128, b: __m128) -> __m128 {
+    cast::<[i32; 4], _>(from_fn(|i| mask(compare(a.0[i], b.0[i], IMM5))))
+}
+
+#[inline]
+pub unsafe fn _mm_blendv_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    blend::<_, f32, 4>(a, b, |i| c.0[i].is_sign_negative())
+}
+
+#[inline]
+pub unsafe fn _mm_movemask_ps(a: __m128) -> i32 { movemask(a.0, f32::is_sign_negative) }
+
+#[inline]
+pub unsafe fn _mm_rcp_ps(a: __m128) -> __m128 { __m128(a.0.map(|a| flush(1.0 / flush(a)))) }
+
+#[inline]
+pub unsafe fn _mm_rsqrt_ps(a: __m128) -> __m128 { __m128(a.0.map(|a| 1.0 / flush(a).sqrt())) }
+
+#[inline]
+pub unsafe fn _mm_and_ps(a: __m128, b: __m128) -> __m128 { zip::<_, u32, 4>(a, b, |a, b| a & b) }
+
+#[inline]
+pub unsafe fn _mm_or_ps(a: __m128, b: __m128) -> __m128 { zip::<_, u32, 4>(a, b, |a, b| a | b) }
+
+#[inline]
+pub unsafe fn _mm_blend_ps<const IMM4: i32>(a: __m128, b: __m128) -> __m128 {
+    blend::<_, f32, 4>(a, b, |i| (IMM4 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm_fmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    __m128(from_fn(|i| a.0[i].mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_fnmadd_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    __m128(from_fn(|i| (-a.0[i]).mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_fnmsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    __m128(from_fn(|i| (-a.0[i]).mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_fmaddsub_ps(a: __m128, b: __m128, c: __m128) -> __m128 {
+    __m128(from_fn(|i| a.0[i].mul_add(b.0[i], if i % 2 == 0 { -c.0[i] } else { c.0[i] })))
+}
+
+#[inline]
+pub unsafe fn _mm_hadd_ps(a: __m128, b: __m128) -> __m128 {
+    __m128([a.0[0] + a.0[1], a.0[2] + a.0[3], b.0[0] + b.0[1], b.0[2] + b.0[3]])
+}
+
+#[inline]
+pub unsafe fn _mm_permute_ps<const IMM8: i32>(a: __m128) -> __m128 {
+    __m128(from_fn(|i| a.0[((IMM8 >> (i * 2)) & 3) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm_permutevar_ps(a: __m128, b: __m128i) -> __m128 {
+    let b = lanes::<_, i32, 4>(b);
+    __m128(from_fn(|i| a.0[(b[i] & 3) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm_unpacklo_ps(a: __m128, b: __m128) -> __m128 { __m128([a.0[0], b.0[0], a.0[1], b.0[1]]) }
+
+#[inline]
+pub unsafe fn _mm_unpackhi_ps(a: __m128, b: __m128) -> __m128 { __m128([a.0[2], b.0[2], a.0[3], b.0[3]]) }
+
+#[inline]
+pub unsafe fn _mm_round_ps<const ROUNDING: i32>(a: __m128) -> __m128 {
+    __m128(a.0.map(|v| round(v as f64, ROUNDING) as f32))
+}
+
+#[inline]
+pub unsafe fn _mm_cvtps_epi32(a: __m128) -> __m128i { cast(a.0.map(|v| cvt_f64_i32(v as f64))) }
+
+#[inline]
+pub unsafe fn _mm_cvttps_epi32(a: __m128) -> __m128i { cast(a.0.map(cvtt_f32_i32)) }
+
+#[inline]
+pub unsafe fn _mm_cvtps_pd(a: __m128) -> __m128d { __m128d([a.0[0] as f64, a.0[1] as f64]) }
+
+// 128-bit double
+
+#[inline]
+pub unsafe fn _mm_set_pd(a: f64, b: f64) -> __m128d { __m128d([b, a]) }
+
+#[inline]
+pub unsafe fn _mm_set1_pd(a: f64) -> __m128d { __m128d([a; 2]) }
+
+#[inline]
+pub unsafe fn _mm_loadu_pd(mem_addr: *const f64) -> __m128d { (mem_addr as *const __m128d).read_unaligned() }
+
+#[inline]
+pub unsafe fn _mm_storeu_pd(mem_addr: *mut f64, a: __m128d) { (mem_addr as *mut __m128d).write_unaligned(a) }
+
+#[inline]
+pub unsafe fn _mm_load_pd(mem_addr: *const f64) -> __m128d { (mem_addr as *const __m128d).read() }
+
+#[inline]
+pub unsafe fn _mm_store_pd(mem_addr: *mut f64, a: __m128d) { (mem_addr as *mut __m128d).write(a) }
+
+#[inline]
+pub unsafe fn _mm_stream_pd(mem_addr: *mut f64, a: __m128d) { _mm_store_pd(mem_addr, a) }
+
+#[inline]
+pub unsafe fn _mm_maskload_pd(mem_addr: *const f64, mask: __m128i) -> __m128d {
+    cast(maskload(mem_addr, lanes::<_, i64, 2>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm_maskstore_pd(mem_addr: *mut f64, mask: __m128i, a: __m128d) {
+    maskstore(mem_addr, lanes::<_, i64, 2>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm_castpd_ps(a: __m128d) -> __m128 { cast(a) }
+
+#[inline]
+pub unsafe fn _mm_castpd_si128(a: __m128d) -> __m128i { cast(a) }
+
+#[inline]
+pub unsafe fn _mm_add_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, f64, 2>(a, b, |a, b| a + b) }
+
+#[inline]
+pub unsafe fn _mm_div_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, f64, 2>(a, b, |a, b| a / b) }
+
+#[inline]
+pub unsafe fn _mm_sqrt_pd(a: __m128d) -> __m128d { __m128d(a.0.map(f64::sqrt)) }
+
+#[inline]
+pub unsafe fn _mm_fmadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    __m128d(from_fn(|i| a.0[i].mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_floor_pd(a: __m128d) -> __m128d { __m128d(a.0.map(f64::floor)) }
+
+#[inline]
+pub unsafe fn _mm_ceil_pd(a: __m128d) -> __m128d { __m128d(a.0.map(f64::ceil)) }
+
+#[inline]
+pub unsafe fn _mm_andnot_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, u64, 2>(a, b, |a, b| !a & b) }
+
+#[inline]
+pub unsafe fn _mm_xor_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, u64, 2>(a, b, |a, b| a ^ b) }
+
+#[inline]
+pub unsafe fn _mm_unpackhi_pd(a: __m128d, b: __m128d) -> __m128d { __m128d([a.0[1], b.0[1]]) }
+
+#[inline]
+pub unsafe fn _mm_setzero_pd() -> __m128d { __m128d([0.0; 2]) }
+
+#[inline]
+pub unsafe fn _mm_and_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, u64, 2>(a, b, |a, b| a & b) }
+
+#[inline]
+pub unsafe fn _mm_or_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, u64, 2>(a, b, |a, b| a | b) }
+
+#[inline]
+pub unsafe fn _mm_blend_pd<const IMM2: i32>(a: __m128d, b: __m128d) -> __m128d {
+    blend::<_, f64, 2>(a, b, |i| (IMM2 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm_fmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    __m128d(from_fn(|i| a.0[i].mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_fnmadd_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    __m128d(from_fn(|i| (-a.0[i]).mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_fnmsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    __m128d(from_fn(|i| (-a.0[i]).mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm_fmaddsub_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    __m128d([a.0[0].mul_add(b.0[0], -c.0[0]), a.0[1].mul_add(b.0[1], c.0[1])])
+}
+
+#[inline]
+pub unsafe fn _mm_hadd_pd(a: __m128d, b: __m128d) -> __m128d { __m128d([a.0[0] + a.0[1], b.0[0] + b.0[1]]) }
+
+#[inline]
+pub unsafe fn _mm_permute_pd<const IMM2: i32>(a: __m128d) -> __m128d {
+    __m128d(from_fn(|i| a.0[((IMM2 >> i) & 1) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm_permutevar_pd(a: __m128d, b: __m128i) -> __m128d {
+    let b = lanes::<_, i64, 2>(b);
+    __m128d(from_fn(|i| a.0[((b[i] >> 1) & 1) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm_round_pd<const ROUNDING: i32>(a: __m128d) -> __m128d { __m128d(a.0.map(|v| round(v, ROUNDING))) }
+
+#[inline]
+pub unsafe fn _mm_cvtpd_epi32(a: __m128d) -> __m128i { cast([cvt_f64_i32(a.0[0]), cvt_f64_i32(a.0[1]), 0, 0]) }
+
+#[inline]
+pub unsafe fn _mm_cvttpd_epi32(a: __m128d) -> __m128i { cast([cvtt_f64_i32(a.0[0]), cvtt_f64_i32(a.0[1]), 0, 0]) }
+
+#[inline]
+pub unsafe fn _mm_cvtpd_ps(a: __m128d) -> __m128 { __m128([a.0[0] as f32, a.0[1] as f32, 0.0, 0.0]) }
+
+#[inline]
+pub unsafe fn _mm_cvtsd_f64(a: __m128d) -> f64 { a.0[0] }
+
+#[inline]
+pub unsafe fn _mm_sub_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, f64, 2>(a, b, |a, b| a - b) }
+
+#[inline]
+pub unsafe fn _mm_mul_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, f64, 2>(a, b, |a, b| a * b) }
+
+#[inline]
+pub unsafe fn _mm_min_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, f64, 2>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_pd(a: __m128d, b: __m128d) -> __m128d { zip::<_, f64, 2>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_cmp_pd<const IMM5: i32>(a: __m128d, b: __m128d) -> __m128d {
+    cast::<[i64; 2], _>(from_fn(|i| mask(compare(a.0[i], b.0[i], IMM5))))
+}
+
+#[inline]
+pub unsafe fn _mm_blendv_pd(a: __m128d, b: __m128d, c: __m128d) -> __m128d {
+    blend::<_, f64, 2>(a, b, |i| c.0[i].is_sign_negative())
+}
+
+#[inline]
+pub unsafe fn _mm_movemask_pd(a: __m128d) -> i32 { movemask(a.0, f64::is_sign_negative) }
+
+// 128-bit integer
+
+#[inline]
+pub unsafe fn _mm_set_epi16(e7: i16, e6: i16, e5: i16, e4: i16, e3: i16, e2: i16, e1: i16, e0: i16) -> __m128i {
+    cast([e0, e1, e2, e3, e4, e5, e6, e7])
+}
+
+#[inline]
+pub unsafe fn _mm_set_epi32(e3: i32, e2: i32, e1: i32, e0: i32) -> __m128i { cast([e0, e1, e2, e3]) }
+
+#[inline]
+pub unsafe fn _mm_extract_epi16<const IMM8: i32>(a: __m128i) -> i32 {
+    lanes::<_, u16, 8>(a)[(IMM8 & 7) as usize] as i32
+}
+
+#[inline]
+pub unsafe fn _mm_insert_epi16<const IMM8: i32>(a: __m128i, i: i32) -> __m128i {
+    let mut r = lanes::<_, i16, 8>(a);
+    r[(IMM8 & 7) as usize] = i as i16;
+    cast(r)
+}
+
+#[inline]
+pub unsafe fn _mm_extract_epi32<const IMM8: i32>(a: __m128i) -> i32 { lanes::<_, i32, 4>(a)[(IMM8 & 3) as usize] }
+
+#[inline]
+pub unsafe fn _mm_insert_epi32<const IMM8: i32>(a: __m128i, i: i32) -> __m128i {
+    let mut r = lanes::<_, i32, 4>(a);
+    r[(IMM8 & 3) as usize] = i;
+    cast(r)
+}
+
+#[inline]
+pub unsafe fn _mm_set_epi64x(e1: i64, e0: i64) -> __m128i { __m128i([e0, e1]) }
+
+#[inline]
+pub unsafe fn _mm_set1_epi16(a: i16) -> __m128i { cast([a; 8]) }
+
+#[inline]
+pub unsafe fn _mm_set1_epi32(a: i32) -> __m128i { cast([a; 4]) }
+
+#[inline]
+pub unsafe fn _mm_set1_epi64x(a: i64) -> __m128i { __m128i([a; 2]) }
+
+#[inline]
+pub unsafe fn _mm_loadu_si128(mem_addr: *const __m128i) -> __m128i { mem_addr.read_unaligned() }
+
+#[inline]
+pub unsafe fn _mm_storeu_si128(mem_addr: *mut __m128i, a: __m128i) { mem_addr.write_unaligned(a) }
+
+#[inline]
+pub unsafe fn _mm_load_si128(mem_addr: *const __m128i) -> __m128i { mem_addr.read() }
+
+#[inline]
+pub unsafe fn _mm_store_si128(mem_addr: *mut __m128i, a: __m128i) { mem_addr.write(a) }
+
+#[inline]
+pub unsafe fn _mm_stream_si128(mem_addr: *mut __m128i, a: __m128i) { _mm_store_si128(mem_addr, a) }
+
+#[inline]
+pub unsafe fn _mm_stream_load_si128(mem_addr: *const __m128i) -> __m128i { _mm_load_si128(mem_addr) }
+
+#[inline]
+pub unsafe fn _mm_maskload_epi32(mem_addr: *const i32, mask: __m128i) -> __m128i {
+    cast(maskload(mem_addr, lanes::<_, i32, 4>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm_maskstore_epi32(mem_addr: *mut i32, mask: __m128i, a: __m128i) {
+    maskstore(mem_addr, lanes::<_, i32, 4>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm_maskload_epi64(mem_addr: *const i64, mask: __m128i) -> __m128i {
+    cast(maskload(mem_addr, lanes::<_, i64, 2>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm_maskstore_epi64(mem_addr: *mut i64, mask: __m128i, a: __m128i) {
+    maskstore(mem_addr, lanes::<_, i64, 2>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm_castsi128_ps(a: __m128i) -> __m128 { cast(a) }
+
+#[inline]
+pub unsafe fn _mm_castsi128_pd(a: __m128i) -> __m128d { cast(a) }
+
+#[inline]
+pub unsafe fn _mm_extract_epi64<const IMM1: i32>(a: __m128i) -> i64 { a.0[(IMM1 & 1) as usize] }
+
+#[inline]
+pub unsafe fn _mm_insert_epi64<const IMM1: i32>(a: __m128i, i: i64) -> __m128i {
+    let mut r = a;
+    r.0[(IMM1 & 1) as usize] = i;
+    r
+}
+
+#[inline]
+pub unsafe fn _mm_packs_epi16(a: __m128i, b: __m128i) -> __m128i {
+    let a = lanes::<_, i16, 8>(a);
+    let b = lanes::<_, i16, 8>(b);
+    cast::<[i8; 16], _>(from_fn(|i| saturate_i8(if i < 8 { a[i] } else { b[i - 8] })))
+}
+
+#[inline]
+pub unsafe fn _mm_shuffle_epi8(a: __m128i, b: __m128i) -> __m128i {
+    let a = lanes::<_, i8, 16>(a);
+    let b = lanes::<_, i8, 16>(b);
+    cast::<[i8; 16], _>(from_fn(|i| if b[i] < 0 { 0 } else { a[(b[i] & 15) as usize] }))
+}
+
+#[inline]
+pub unsafe fn _mm_blendv_epi8(a: __m128i, b: __m128i, mask: __m128i) -> __m128i {
+    let mask = lanes::<_, i8, 16>(mask);
+    blend::<_, i8, 16>(a, b, |i| mask[i] < 0)
+}
+
+#[inline]
+pub unsafe fn _mm_movemask_epi8(a: __m128i) -> i32 { movemask(lanes::<_, i8, 16>(a), |v| v < 0) }
+
+#[inline]
+pub unsafe fn _mm_testz_si128(a: __m128i, b: __m128i) -> i32 { (0..2).all(|i| a.0[i] & b.0[i] == 0) as i32 }
+
+#[inline]
+pub unsafe fn _mm_and_si128(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, |a, b| a & b) }
+
+#[inline]
+pub unsafe fn _mm_or_si128(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, |a, b| a | b) }
+
+#[inline]
+pub unsafe fn _mm_xor_si128(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, |a, b| a ^ b) }
+
+#[inline]
+pub unsafe fn _mm_cmpeq_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm_cmpgt_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm_cmpeq_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm_cmpgt_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm_cmpeq_epi64(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm_cmpgt_epi64(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm_min_epi8(a: __m128i, b: __m128i) -> __m128i { zip::<_, i8, 16>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_epi8(a: __m128i, b: __m128i) -> __m128i { zip::<_, i8, 16>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_min_epu8(a: __m128i, b: __m128i) -> __m128i { zip::<_, u8, 16>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_epu8(a: __m128i, b: __m128i) -> __m128i { zip::<_, u8, 16>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_min_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_min_epu16(a: __m128i, b: __m128i) -> __m128i { zip::<_, u16, 8>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_epu16(a: __m128i, b: __m128i) -> __m128i { zip::<_, u16, 8>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_min_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_min_epu32(a: __m128i, b: __m128i) -> __m128i { zip::<_, u32, 4>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm_max_epu32(a: __m128i, b: __m128i) -> __m128i { zip::<_, u32, 4>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm_add_epi64(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, i64::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm_sub_epi64(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, i64::wrapping_sub) }
+
+#[inline]
+pub unsafe fn _mm_setzero_si128() -> __m128i { __m128i([0; 2]) }
+
+#[inline]
+pub unsafe fn _mm_cvtsi32_si128(a: i32) -> __m128i { __m128i([a as u32 as i64, 0]) }
+
+#[inline]
+pub unsafe fn _mm_andnot_si128(a: __m128i, b: __m128i) -> __m128i { zip::<_, i64, 2>(a, b, |a, b| !a & b) }
+
+#[inline]
+pub unsafe fn _mm_slli_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, u16, 8>(a).map(|v| v.checked_shl(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srli_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, u16, 8>(a).map(|v| v.checked_shr(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_sll_epi16(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, u16, 8>(a).map(|v| v.checked_shl(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srl_epi16(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, u16, 8>(a).map(|v| v.checked_shr(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srai_epi16<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, i16, 8>(a).map(|v| v >> (IMM8 as u32).min(15)))
+}
+
+#[inline]
+pub unsafe fn _mm_sra_epi16(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, i16, 8>(a).map(|v| v >> shift_count(count).min(15)))
+}
+
+#[inline]
+pub unsafe fn _mm_slli_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, u32, 4>(a).map(|v| v.checked_shl(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srli_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, u32, 4>(a).map(|v| v.checked_shr(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_sll_epi32(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, u32, 4>(a).map(|v| v.checked_shl(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srl_epi32(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, u32, 4>(a).map(|v| v.checked_shr(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srai_epi32<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, i32, 4>(a).map(|v| v >> (IMM8 as u32).min(31)))
+}
+
+#[inline]
+pub unsafe fn _mm_sra_epi32(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, i32, 4>(a).map(|v| v >> shift_count(count).min(31)))
+}
+
+#[inline]
+pub unsafe fn _mm_sllv_epi32(a: __m128i, count: __m128i) -> __m128i {
+    zip::<_, u32, 4>(a, count, |v, c| if c < 32 { v << c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm_srlv_epi32(a: __m128i, count: __m128i) -> __m128i {
+    zip::<_, u32, 4>(a, count, |v, c| if c < 32 { v >> c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm_srav_epi32(a: __m128i, count: __m128i) -> __m128i {
+    zip::<_, i32, 4>(a, count, |v, c| v >> (c as u32).min(31))
+}
+
+#[inline]
+pub unsafe fn _mm_slli_epi64<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, u64, 2>(a).map(|v| v.checked_shl(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srli_epi64<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast(lanes::<_, u64, 2>(a).map(|v| v.checked_shr(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srli_si128<const IMM8: i32>(a: __m128i) -> __m128i {
+    cast((u128::from_le_bytes(cast(a))).checked_shr(IMM8 as u32 * 8).unwrap_or(0).to_le_bytes())
+}
+
+#[inline]
+pub unsafe fn _mm_cvtsi128_si32(a: __m128i) -> i32 { lanes::<_, i32, 4>(a)[0] }
+
+#[inline]
+pub unsafe fn _mm_cvtsi128_si64(a: __m128i) -> i64 { lanes::<_, i64, 2>(a)[0] }
+
+#[inline]
+pub unsafe fn _mm_cvtepi32_epi64(a: __m128i) -> __m128i {
+    let a = lanes::<_, i32, 4>(a);
+    cast([a[0] as i64, a[1] as i64])
+}
+
+#[inline]
+pub unsafe fn _mm_cvtepu16_epi32(a: __m128i) -> __m128i {
+    let a = lanes::<_, u16, 8>(a);
+    cast([a[0] as u32, a[1] as u32, a[2] as u32, a[3] as u32])
+}
+
+#[inline]
+pub unsafe fn _mm_cvtepu32_epi64(a: __m128i) -> __m128i {
+    let a = lanes::<_, u32, 4>(a);
+    cast([a[0] as u64, a[1] as u64])
+}
+
+#[inline]
+pub unsafe fn _mm_sll_epi64(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, u64, 2>(a).map(|v| v.checked_shl(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_srl_epi64(a: __m128i, count: __m128i) -> __m128i {
+    cast(lanes::<_, u64, 2>(a).map(|v| v.checked_shr(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm_sllv_epi64(a: __m128i, count: __m128i) -> __m128i {
+    zip::<_, u64, 2>(a, count, |v, c| if c < 64 { v << c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm_srlv_epi64(a: __m128i, count: __m128i) -> __m128i {
+    zip::<_, u64, 2>(a, count, |v, c| if c < 64 { v >> c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm_add_epi8(a: __m128i, b: __m128i) -> __m128i { zip::<_, i8, 16>(a, b, i8::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm_add_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, i16::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm_sub_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, i16::wrapping_sub) }
+
+#[inline]
+pub unsafe fn _mm_mullo_epi16(a: __m128i, b: __m128i) -> __m128i { zip::<_, i16, 8>(a, b, i16::wrapping_mul) }
+
+#[inline]
+pub unsafe fn _mm_madd_epi16(a: __m128i, b: __m128i) -> __m128i {
+    let a = lanes::<_, i16, 8>(a);
+    let b = lanes::<_, i16, 8>(b);
+    cast(from_fn::<i32, 4, _>(|i| {
+        (a[2 * i] as i32 * b[2 * i] as i32).wrapping_add(a[2 * i + 1] as i32 * b[2 * i + 1] as i32)
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm_blend_epi16<const IMM8: i32>(a: __m128i, b: __m128i) -> __m128i {
+    blend::<_, i16, 8>(a, b, |i| (IMM8 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm_blend_epi32<const IMM4: i32>(a: __m128i, b: __m128i) -> __m128i {
+    blend::<_, i32, 4>(a, b, |i| (IMM4 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm_packs_epi32(a: __m128i, b: __m128i) -> __m128i {
+    let a = lanes::<_, i32, 4>(a);
+    let b = lanes::<_, i32, 4>(b);
+    cast::<[i16; 8], _>(from_fn(|i| saturate_i16(if i < 4 { a[i] } else { b[i - 4] })))
+}
+
+#[inline]
+pub unsafe fn _mm_alignr_epi8<const IMM: i32>(a: __m128i, b: __m128i) -> __m128i {
+    let a = lanes::<_, u8, 16>(a);
+    let b = lanes::<_, u8, 16>(b);
+    // Shifts the concatenation of `a` and `b` right by IMM bytes
+    cast::<[u8; 16], _>(from_fn(|i| match i + IMM as usize {
+        j @ 0..=15 => b[j],
+        j @ 16..=31 => a[j - 16],
+        _ => 0,
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm_cvtepi32_ps(a: __m128i) -> __m128 { __m128(lanes::<_, i32, 4>(a).map(|v| v as f32)) }
+
+#[inline]
+pub unsafe fn _mm_add_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, i32::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm_sub_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, i32::wrapping_sub) }
+
+#[inline]
+pub unsafe fn _mm_mullo_epi32(a: __m128i, b: __m128i) -> __m128i { zip::<_, i32, 4>(a, b, i32::wrapping_mul) }
+
+// 256-bit float
+
+#[inline]
+pub unsafe fn _mm256_set_ps(e7: f32, e6: f32, e5: f32, e4: f32, e3: f32, e2: f32, e1: f32, e0: f32) -> __m256 {
+    __m256([e0, e1, e2, e3, e4, e5, e6, e7])
+}
+
+#[inline]
+pub unsafe fn _mm256_set1_ps(a: f32) -> __m256 { __m256([a; 8]) }
+
+#[inline]
+pub unsafe fn _mm256_loadu_ps(mem_addr: *const f32) -> __m256 { (mem_addr as *const __m256).read_unaligned() }
+
+#[inline]
+pub unsafe fn _mm256_storeu_ps(mem_addr: *mut f32, a: __m256) { (mem_addr as *mut __m256).write_unaligned(a) }
+
+#[inline]
+pub unsafe fn _mm256_load_ps(mem_addr: *const f32) -> __m256 { (mem_addr as *const __m256).read() }
+
+#[inline]
+pub unsafe fn _mm256_store_ps(mem_addr: *mut f32, a: __m256) { (mem_addr as *mut __m256).write(a) }
+
+#[inline]
+pub unsafe fn _mm256_stream_ps(mem_addr: *mut f32, a: __m256) { _mm256_store_ps(mem_addr, a) }
+
+#[inline]
+pub unsafe fn _mm256_maskload_ps(mem_addr: *const f32, mask: __m256i) -> __m256 {
+    cast(maskload(mem_addr, lanes::<_, i32, 8>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm256_maskstore_ps(mem_addr: *mut f32, mask: __m256i, a: __m256) {
+    maskstore(mem_addr, lanes::<_, i32, 8>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm256_storeu2_m128(hiaddr: *mut f32, loaddr: *mut f32, a: __m256) {
+    (loaddr as *mut __m128).write_unaligned(_mm256_castps256_ps128(a));
+    (hiaddr as *mut __m128).write_unaligned(_mm256_extractf128_ps::<1>(a));
+}
+
+#[inline]
+pub unsafe fn _mm256_i32gather_ps<const SCALE: i32>(slice: *const f32, offsets: __m256i) -> __m256 {
+    __m256(gather(slice, lanes::<_, i32, 8>(offsets), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_mask_i32gather_ps<const SCALE: i32>(
+    src: __m256,
+    slice: *const f32,
+    offsets: __m256i,
+    mask: __m256,
+) -> __m256 {
+    __m256(mask_gather(src.0, slice, lanes::<_, i32, 8>(offsets), lanes::<_, i32, 8>(mask), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_castps_si256(a: __m256) -> __m256i { cast(a) }
+
+#[inline]
+pub unsafe fn _mm256_castps_pd(a: __m256) -> __m256d { cast(a) }
+
+#[inline]
+pub unsafe fn _mm256_castps256_ps128(a: __m256) -> __m128 { __m128([a.0[0], a.0[1], a.0[2], a.0[3]]) }
+
+#[inline]
+pub unsafe fn _mm256_extractf128_ps<const IMM1: i32>(a: __m256) -> __m128 {
+    let o = (IMM1 as usize & 1) * 4;
+    __m128(from_fn(|i| a.0[o + i]))
+}
+
+#[inline]
+pub unsafe fn _mm256_set_m128(hi: __m128, lo: __m128) -> __m256 { cast([lo, hi]) }
+
+#[inline]
+pub unsafe fn _mm256_cvttps_epi32(a: __m256) -> __m256i { cast(a.0.map(cvtt_f32_i32)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtps_epi32(a: __m256) -> __m256i { cast(a.0.map(|v| cvt_f64_i32(v as f64))) }
+
+#[inline]
+pub unsafe fn _mm256_floor_ps(a: __m256) -> __m256 { __m256(a.0.map(f32::floor)) }
+
+#[inline]
+pub unsafe fn _mm256_ceil_ps(a: __m256) -> __m256 { __m256(a.0.map(f32::ceil)) }
+
+#[inline]
+pub unsafe fn _mm256_round_ps<const ROUNDING: i32>(a: __m256) -> __m256 {
+    __m256(a.0.map(|v| round(v as f64, ROUNDING) as f32))
+}
+
+#[inline]
+pub unsafe fn _mm256_and_ps(a: __m256, b: __m256) -> __m256 { zip::<_, u32, 8>(a, b, |a, b| a & b) }
+
+#[inline]
+pub unsafe fn _mm256_andnot_ps(a: __m256, b: __m256) -> __m256 { zip::<_, u32, 8>(a, b, |a, b| !a & b) }
+
+#[inline]
+pub unsafe fn _mm256_or_ps(a: __m256, b: __m256) -> __m256 { zip::<_, u32, 8>(a, b, |a, b| a | b) }
+
+#[inline]
+pub unsafe fn _mm256_permute_ps<const IMM8: i32>(a: __m256) -> __m256 {
+    __m256(from_fn(|i| a.0[(i & 4) + ((IMM8 >> ((i & 3) * 2)) & 3) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_permutevar8x32_ps(a: __m256, idx: __m256i) -> __m256 {
+    let idx = lanes::<_, i32, 8>(idx);
+    __m256(from_fn(|i| a.0[(idx[i] & 7) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_unpacklo_ps(a: __m256, b: __m256) -> __m256 {
+    __m256(from_fn(|i| {
+        let j = (i & 4) + (i & 3) / 2;
+        if i & 1 == 0 {
+            a.0[j]
+        } else {
+            b.0[j]
+        }
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_unpackhi_ps(a: __m256, b: __m256) -> __m256 {
+    __m256(from_fn(|i| {
+        let j = (i & 4) + 2 + (i & 3) / 2;
+        if i & 1 == 0 {
+            a.0[j]
+        } else {
+            b.0[j]
+        }
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_blend_ps<const IMM8: i32>(a: __m256, b: __m256) -> __m256 {
+    blend::<_, f32, 8>(a, b, |i| (IMM8 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm256_hadd_ps(a: __m256, b: __m256) -> __m256 {
+    __m256(from_fn(|i| {
+        let s = if i & 2 == 0 { a.0 } else { b.0 };
+        let j = (i & 4) + (i & 1) * 2;
+        s[j] + s[j + 1]
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_add_ps(a: __m256, b: __m256) -> __m256 { zip::<_, f32, 8>(a, b, |a, b| a + b) }
+
+#[inline]
+pub unsafe fn _mm256_sub_ps(a: __m256, b: __m256) -> __m256 { zip::<_, f32, 8>(a, b, |a, b| a - b) }
+
+#[inline]
+pub unsafe fn _mm256_mul_ps(a: __m256, b: __m256) -> __m256 { zip::<_, f32, 8>(a, b, |a, b| a * b) }
+
+#[inline]
+pub unsafe fn _mm256_div_ps(a: __m256, b: __m256) -> __m256 { zip::<_, f32, 8>(a, b, |a, b| a / b) }
+
+#[inline]
+pub unsafe fn _mm256_sqrt_ps(a: __m256) -> __m256 { __m256(a.0.map(f32::sqrt)) }
+
+#[inline]
+pub unsafe fn _mm256_rcp_ps(a: __m256) -> __m256 { __m256(a.0.map(|a| flush(1.0 / flush(a)))) }
+
+#[inline]
+pub unsafe fn _mm256_rsqrt_ps(a: __m256) -> __m256 { __m256(a.0.map(|a| 1.0 / flush(a).sqrt())) }
+
+#[inline]
+pub unsafe fn _mm256_fnmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    __m256(from_fn(|i| (-a.0[i]).mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_fmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    __m256(from_fn(|i| a.0[i].mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_fnmsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    __m256(from_fn(|i| (-a.0[i]).mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_fmaddsub_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    __m256(from_fn(|i| a.0[i].mul_add(b.0[i], if i % 2 == 0 { -c.0[i] } else { c.0[i] })))
+}
+
+#[inline]
+pub unsafe fn _mm256_xor_ps(a: __m256, b: __m256) -> __m256 { zip::<_, u32, 8>(a, b, |a, b| a ^ b) }
+
+#[inline]
+pub unsafe fn _mm256_fmadd_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    __m256(from_fn(|i| a.0[i].mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_min_ps(a: __m256, b: __m256) -> __m256 { zip::<_, f32, 8>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_ps(a: __m256, b: __m256) -> __m256 { zip::<_, f32, 8>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_cmp_ps<const IMM5: i32>(a: __m256, b: __m256) -> __m256 {
+    cast::<[i32; 8], _>(from_fn(|i| mask(compare(a.0[i], b.0[i], IMM5))))
+}
+
+#[inline]
+pub unsafe fn _mm256_blendv_ps(a: __m256, b: __m256, c: __m256) -> __m256 {
+    blend::<_, f32, 8>(a, b, |i| c.0[i].is_sign_negative())
+}
+
+#[inline]
+pub unsafe fn _mm256_movemask_ps(a: __m256) -> i32 { movemask(a.0, f32::is_sign_negative) }
+
+// 256-bit double
+
+#[inline]
+pub unsafe fn _mm256_set_pd(a: f64, b: f64, c: f64, d: f64) -> __m256d { __m256d([d, c, b, a]) }
+
+#[inline]
+pub unsafe fn _mm256_div_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, f64, 4>(a, b, |a, b| a / b) }
+
+#[inline]
+pub unsafe fn _mm256_sqrt_pd(a: __m256d) -> __m256d { __m256d(a.0.map(f64::sqrt)) }
+
+#[inline]
+pub unsafe fn _mm256_fmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    __m256d(from_fn(|i| a.0[i].mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_fmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    __m256d(from_fn(|i| a.0[i].mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_fnmsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    __m256d(from_fn(|i| (-a.0[i]).mul_add(b.0[i], -c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_fmaddsub_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    __m256d(from_fn(|i| a.0[i].mul_add(b.0[i], if i % 2 == 0 { -c.0[i] } else { c.0[i] })))
+}
+
+#[inline]
+pub unsafe fn _mm256_xor_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, u64, 4>(a, b, |a, b| a ^ b) }
+
+#[inline]
+pub unsafe fn _mm256_fnmadd_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    __m256d(from_fn(|i| (-a.0[i]).mul_add(b.0[i], c.0[i])))
+}
+
+#[inline]
+pub unsafe fn _mm256_cvtpd_ps(a: __m256d) -> __m128 { __m128(a.0.map(|a| a as f32)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtps_pd(a: __m128) -> __m256d { __m256d(a.0.map(f64::from)) }
+
+#[inline]
+pub unsafe fn _mm256_set1_pd(a: f64) -> __m256d { __m256d([a; 4]) }
+
+#[inline]
+pub unsafe fn _mm256_setzero_pd() -> __m256d { __m256d([0.0; 4]) }
+
+#[inline]
+pub unsafe fn _mm256_loadu_pd(mem_addr: *const f64) -> __m256d { (mem_addr as *const __m256d).read_unaligned() }
+
+#[inline]
+pub unsafe fn _mm256_storeu_pd(mem_addr: *mut f64, a: __m256d) { (mem_addr as *mut __m256d).write_unaligned(a) }
+
+#[inline]
+pub unsafe fn _mm256_load_pd(mem_addr: *const f64) -> __m256d { (mem_addr as *const __m256d).read() }
+
+#[inline]
+pub unsafe fn _mm256_store_pd(mem_addr: *mut f64, a: __m256d) { (mem_addr as *mut __m256d).write(a) }
+
+#[inline]
+pub unsafe fn _mm256_stream_pd(mem_addr: *mut f64, a: __m256d) { _mm256_store_pd(mem_addr, a) }
+
+#[inline]
+pub unsafe fn _mm256_maskload_pd(mem_addr: *const f64, mask: __m256i) -> __m256d {
+    cast(maskload(mem_addr, lanes::<_, i64, 4>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm256_i32gather_pd<const SCALE: i32>(slice: *const f64, offsets: __m128i) -> __m256d {
+    __m256d(gather(slice, lanes::<_, i32, 4>(offsets), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_mask_i32gather_pd<const SCALE: i32>(
+    src: __m256d,
+    slice: *const f64,
+    offsets: __m128i,
+    mask: __m256d,
+) -> __m256d {
+    __m256d(mask_gather(src.0, slice, lanes::<_, i32, 4>(offsets), lanes::<_, i64, 4>(mask), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_i64gather_pd<const SCALE: i32>(slice: *const f64, offsets: __m256i) -> __m256d {
+    __m256d(gather(slice, offsets.0, SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_mask_i64gather_pd<const SCALE: i32>(
+    src: __m256d,
+    slice: *const f64,
+    offsets: __m256i,
+    mask: __m256d,
+) -> __m256d {
+    __m256d(mask_gather(src.0, slice, offsets.0, lanes::<_, i64, 4>(mask), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_maskstore_pd(mem_addr: *mut f64, mask: __m256i, a: __m256d) {
+    maskstore(mem_addr, lanes::<_, i64, 4>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm256_castpd_ps(a: __m256d) -> __m256 { cast(a) }
+
+#[inline]
+pub unsafe fn _mm256_castpd_si256(a: __m256d) -> __m256i { cast(a) }
+
+#[inline]
+pub unsafe fn _mm256_castpd256_pd128(a: __m256d) -> __m128d { __m128d([a.0[0], a.0[1]]) }
+
+#[inline]
+pub unsafe fn _mm256_extractf128_pd<const IMM1: i32>(a: __m256d) -> __m128d {
+    let o = (IMM1 as usize & 1) * 2;
+    __m128d([a.0[o], a.0[o + 1]])
+}
+
+#[inline]
+pub unsafe fn _mm256_set_m128d(hi: __m128d, lo: __m128d) -> __m256d { cast([lo, hi]) }
+
+#[inline]
+pub unsafe fn _mm256_floor_pd(a: __m256d) -> __m256d { __m256d(a.0.map(f64::floor)) }
+
+#[inline]
+pub unsafe fn _mm256_ceil_pd(a: __m256d) -> __m256d { __m256d(a.0.map(f64::ceil)) }
+
+#[inline]
+pub unsafe fn _mm256_round_pd<const ROUNDING: i32>(a: __m256d) -> __m256d { __m256d(a.0.map(|v| round(v, ROUNDING))) }
+
+#[inline]
+pub unsafe fn _mm256_cvttpd_epi32(a: __m256d) -> __m128i { cast(a.0.map(cvtt_f64_i32)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtpd_epi32(a: __m256d) -> __m128i { cast(a.0.map(cvt_f64_i32)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepi32_pd(a: __m128i) -> __m256d { __m256d(lanes::<_, i32, 4>(a).map(f64::from)) }
+
+#[inline]
+pub unsafe fn _mm256_and_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, u64, 4>(a, b, |a, b| a & b) }
+
+#[inline]
+pub unsafe fn _mm256_andnot_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, u64, 4>(a, b, |a, b| !a & b) }
+
+#[inline]
+pub unsafe fn _mm256_or_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, u64, 4>(a, b, |a, b| a | b) }
+
+#[inline]
+pub unsafe fn _mm256_permute_pd<const IMM4: i32>(a: __m256d) -> __m256d {
+    __m256d(from_fn(|i| a.0[(i & 2) + ((IMM4 >> i) & 1) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_permute4x64_pd<const IMM8: i32>(a: __m256d) -> __m256d {
+    __m256d(from_fn(|i| a.0[((IMM8 >> (i * 2)) & 3) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_permutevar_pd(a: __m256d, b: __m256i) -> __m256d {
+    let b = lanes::<_, i64, 4>(b);
+    __m256d(from_fn(|i| a.0[(i & 2) + ((b[i] >> 1) & 1) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_blend_pd<const IMM4: i32>(a: __m256d, b: __m256d) -> __m256d {
+    blend::<_, f64, 4>(a, b, |i| (IMM4 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm256_hadd_pd(a: __m256d, b: __m256d) -> __m256d {
+    __m256d([a.0[0] + a.0[1], b.0[0] + b.0[1], a.0[2] + a.0[3], b.0[2] + b.0[3]])
+}
+
+#[inline]
+pub unsafe fn _mm256_add_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, f64, 4>(a, b, |a, b| a + b) }
+
+#[inline]
+pub unsafe fn _mm256_sub_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, f64, 4>(a, b, |a, b| a - b) }
+
+#[inline]
+pub unsafe fn _mm256_mul_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, f64, 4>(a, b, |a, b| a * b) }
+
+#[inline]
+pub unsafe fn _mm256_min_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, f64, 4>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_pd(a: __m256d, b: __m256d) -> __m256d { zip::<_, f64, 4>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_cmp_pd<const IMM5: i32>(a: __m256d, b: __m256d) -> __m256d {
+    cast::<[i64; 4], _>(from_fn(|i| mask(compare(a.0[i], b.0[i], IMM5))))
+}
+
+#[inline]
+pub unsafe fn _mm256_blendv_pd(a: __m256d, b: __m256d, c: __m256d) -> __m256d {
+    blend::<_, f64, 4>(a, b, |i| c.0[i].is_sign_negative())
+}
+
+#[inline]
+pub unsafe fn _mm256_movemask_pd(a: __m256d) -> i32 { movemask(a.0, f64::is_sign_negative) }
+
+// 256-bit integer
+
+#[inline]
+pub unsafe fn _mm256_set_epi8(
+    e31: i8,
+    e30: i8,
+    e29: i8,
+    e28: i8,
+    e27: i8,
+    e26: i8,
+    e25: i8,
+    e24: i8,
+    e23: i8,
+    e22: i8,
+    e21: i8,
+    e20: i8,
+    e19: i8,
+    e18: i8,
+    e17: i8,
+    e16: i8,
+    e15: i8,
+    e14: i8,
+    e13: i8,
+    e12: i8,
+    e11: i8,
+    e10: i8,
+    e9: i8,
+    e8: i8,
+    e7: i8,
+    e6: i8,
+    e5: i8,
+    e4: i8,
+    e3: i8,
+    e2: i8,
+    e1: i8,
+    e0: i8,
+) -> __m256i {
+    cast([
+        e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15, e16, e17, e18, e19, e20, e21, e22, e23,
+        e24, e25, e26, e27, e28, e29, e30, e31,
+    ])
+}
+
+#[inline]
+pub unsafe fn _mm256_set_epi16(
+    e15: i16,
+    e14: i16,
+    e13: i16,
+    e12: i16,
+    e11: i16,
+    e10: i16,
+    e9: i16,
+    e8: i16,
+    e7: i16,
+    e6: i16,
+    e5: i16,
+    e4: i16,
+    e3: i16,
+    e2: i16,
+    e1: i16,
+    e0: i16,
+) -> __m256i {
+    cast([e0, e1, e2, e3, e4, e5, e6, e7, e8, e9, e10, e11, e12, e13, e14, e15])
+}
+
+#[inline]
+pub unsafe fn _mm256_set_epi32(e7: i32, e6: i32, e5: i32, e4: i32, e3: i32, e2: i32, e1: i32, e0: i32) -> __m256i {
+    cast([e0, e1, e2, e3, e4, e5, e6, e7])
+}
+
+#[inline]
+pub unsafe fn _mm256_set_epi64x(a: i64, b: i64, c: i64, d: i64) -> __m256i { __m256i([d, c, b, a]) }
+
+#[inline]
+pub unsafe fn _mm256_set1_epi64x(a: i64) -> __m256i { __m256i([a; 4]) }
+
+#[inline]
+pub unsafe fn _mm256_set1_epi8(a: i8) -> __m256i { cast([a; 32]) }
+
+#[inline]
+pub unsafe fn _mm256_set1_epi16(a: i16) -> __m256i { cast([a; 16]) }
+
+#[inline]
+pub unsafe fn _mm256_set1_epi32(a: i32) -> __m256i { cast([a; 8]) }
+
+#[inline]
+pub unsafe fn _mm256_loadu_si256(mem_addr: *const __m256i) -> __m256i { mem_addr.read_unaligned() }
+
+#[inline]
+pub unsafe fn _mm256_storeu_si256(mem_addr: *mut __m256i, a: __m256i) { mem_addr.write_unaligned(a) }
+
+#[inline]
+pub unsafe fn _mm256_load_si256(mem_addr: *const __m256i) -> __m256i { mem_addr.read() }
+
+#[inline]
+pub unsafe fn _mm256_store_si256(mem_addr: *mut __m256i, a: __m256i) { mem_addr.write(a) }
+
+#[inline]
+pub unsafe fn _mm256_stream_si256(mem_addr: *mut __m256i, a: __m256i) { _mm256_store_si256(mem_addr, a) }
+
+#[inline]
+pub unsafe fn _mm256_stream_load_si256(mem_addr: *const __m256i) -> __m256i { _mm256_load_si256(mem_addr) }
+
+#[inline]
+pub unsafe fn _mm256_maskload_epi32(mem_addr: *const i32, mask: __m256i) -> __m256i {
+    cast(maskload(mem_addr, lanes::<_, i32, 8>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm256_maskstore_epi32(mem_addr: *mut i32, mask: __m256i, a: __m256i) {
+    maskstore(mem_addr, lanes::<_, i32, 8>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm256_maskload_epi64(mem_addr: *const i64, mask: __m256i) -> __m256i {
+    cast(maskload(mem_addr, lanes::<_, i64, 4>(mask)))
+}
+
+#[inline]
+pub unsafe fn _mm256_maskstore_epi64(mem_addr: *mut i64, mask: __m256i, a: __m256i) {
+    maskstore(mem_addr, lanes::<_, i64, 4>(mask), lanes(a))
+}
+
+#[inline]
+pub unsafe fn _mm256_i32gather_epi32<const SCALE: i32>(slice: *const i32, offsets: __m256i) -> __m256i {
+    cast(gather(slice, lanes::<_, i32, 8>(offsets), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_mask_i32gather_epi32<const SCALE: i32>(
+    src: __m256i,
+    slice: *const i32,
+    offsets: __m256i,
+    mask: __m256i,
+) -> __m256i {
+    cast(mask_gather(lanes::<_, i32, 8>(src), slice, lanes::<_, i32, 8>(offsets), lanes::<_, i32, 8>(mask), SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_i64gather_epi64<const SCALE: i32>(slice: *const i64, offsets: __m256i) -> __m256i {
+    __m256i(gather(slice, offsets.0, SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_mask_i64gather_epi64<const SCALE: i32>(
+    src: __m256i,
+    slice: *const i64,
+    offsets: __m256i,
+    mask: __m256i,
+) -> __m256i {
+    __m256i(mask_gather(src.0, slice, offsets.0, mask.0, SCALE))
+}
+
+#[inline]
+pub unsafe fn _mm256_castsi256_si128(a: __m256i) -> __m128i { __m128i([a.0[0], a.0[1]]) }
+
+#[inline]
+pub unsafe fn _mm256_extracti128_si256<const IMM1: i32>(a: __m256i) -> __m128i {
+    let o = (IMM1 as usize & 1) * 2;
+    __m128i([a.0[o], a.0[o + 1]])
+}
+
+#[inline]
+pub unsafe fn _mm256_set_m128i(hi: __m128i, lo: __m128i) -> __m256i { cast([lo, hi]) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepi8_epi16(a: __m128i) -> __m256i { cast(lanes::<_, i8, 16>(a).map(|v| v as i16)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepu8_epi16(a: __m128i) -> __m256i { cast(lanes::<_, u8, 16>(a).map(|v| v as u16)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepu16_epi32(a: __m128i) -> __m256i { cast(lanes::<_, u16, 8>(a).map(|v| v as u32)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepu32_epi64(a: __m128i) -> __m256i { cast(lanes::<_, u32, 4>(a).map(|v| v as u64)) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepi32_epi64(a: __m128i) -> __m256i { cast(lanes::<_, i32, 4>(a).map(|v| v as i64)) }
+
+#[inline]
+pub unsafe fn _mm256_castsi256_ps(a: __m256i) -> __m256 { cast(a) }
+
+#[inline]
+pub unsafe fn _mm256_castsi256_pd(a: __m256i) -> __m256d { cast(a) }
+
+#[inline]
+pub unsafe fn _mm256_cvtepi32_ps(a: __m256i) -> __m256 { __m256(lanes::<_, i32, 8>(a).map(|v| v as f32)) }
+
+#[inline]
+pub unsafe fn _mm256_extract_epi8<const INDEX: i32>(a: __m256i) -> i32 {
+    lanes::<_, u8, 32>(a)[(INDEX & 31) as usize] as i32
+}
+
+#[inline]
+pub unsafe fn _mm256_insert_epi8<const INDEX: i32>(a: __m256i, i: i8) -> __m256i {
+    let mut r = lanes::<_, i8, 32>(a);
+    r[(INDEX & 31) as usize] = i;
+    cast(r)
+}
+
+#[inline]
+pub unsafe fn _mm256_extract_epi16<const INDEX: i32>(a: __m256i) -> i32 {
+    lanes::<_, u16, 16>(a)[(INDEX & 15) as usize] as i32
+}
+
+#[inline]
+pub unsafe fn _mm256_insert_epi16<const INDEX: i32>(a: __m256i, i: i16) -> __m256i {
+    let mut r = lanes::<_, i16, 16>(a);
+    r[(INDEX & 15) as usize] = i;
+    cast(r)
+}
+
+#[inline]
+pub unsafe fn _mm256_extract_epi32<const INDEX: i32>(a: __m256i) -> i32 { lanes::<_, i32, 8>(a)[(INDEX & 7) as usize] }
+
+#[inline]
+pub unsafe fn _mm256_insert_epi32<const INDEX: i32>(a: __m256i, i: i32) -> __m256i {
+    let mut r = lanes::<_, i32, 8>(a);
+    r[(INDEX & 7) as usize] = i;
+    cast(r)
+}
+
+#[inline]
+pub unsafe fn _mm256_extract_epi64<const INDEX: i32>(a: __m256i) -> i64 { a.0[(INDEX & 3) as usize] }
+
+#[inline]
+pub unsafe fn _mm256_insert_epi64<const INDEX: i32>(a: __m256i, i: i64) -> __m256i {
+    let mut r = a;
+    r.0[(INDEX & 3) as usize] = i;
+    r
+}
+
+#[inline]
+pub unsafe fn _mm256_blend_epi16<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i {
+    blend::<_, i16, 16>(a, b, |i| (IMM8 >> (i & 7)) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm256_blend_epi32<const IMM8: i32>(a: __m256i, b: __m256i) -> __m256i {
+    blend::<_, i32, 8>(a, b, |i| (IMM8 >> i) & 1 != 0)
+}
+
+#[inline]
+pub unsafe fn _mm256_permute4x64_epi64<const IMM8: i32>(a: __m256i) -> __m256i {
+    __m256i(from_fn(|i| a.0[((IMM8 >> (i * 2)) & 3) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_packs_epi32(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, i32, 8>(a);
+    let b = lanes::<_, i32, 8>(b);
+
+    cast::<[i16; 16], _>(from_fn(|i| {
+        let s = if i & 4 == 0 { a } else { b };
+        saturate_i16(s[(i & 8) / 2 + (i & 3)])
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_packs_epi16(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, i16, 16>(a);
+    let b = lanes::<_, i16, 16>(b);
+
+    cast::<[i8; 32], _>(from_fn(|i| {
+        let s = if i & 8 == 0 { a } else { b };
+        saturate_i8(s[(i & 16) / 2 + (i & 7)])
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_blendv_epi8(a: __m256i, b: __m256i, mask: __m256i) -> __m256i {
+    let mask = lanes::<_, i8, 32>(mask);
+    blend::<_, i8, 32>(a, b, |i| mask[i] < 0)
+}
+
+#[inline]
+pub unsafe fn _mm256_movemask_epi8(a: __m256i) -> i32 { movemask(lanes::<_, i8, 32>(a), |v| v < 0) }
+
+#[inline]
+pub unsafe fn _mm256_testz_si256(a: __m256i, b: __m256i) -> i32 { (0..4).all(|i| a.0[i] & b.0[i] == 0) as i32 }
+
+#[inline]
+pub unsafe fn _mm256_cmpeq_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpgt_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpeq_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpgt_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpeq_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpgt_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpeq_epi64(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, |a, b| mask(a == b)) }
+
+#[inline]
+pub unsafe fn _mm256_cmpgt_epi64(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, |a, b| mask(a > b)) }
+
+#[inline]
+pub unsafe fn _mm256_and_si256(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, |a, b| a & b) }
+
+#[inline]
+pub unsafe fn _mm256_or_si256(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, |a, b| a | b) }
+
+#[inline]
+pub unsafe fn _mm256_xor_si256(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, |a, b| a ^ b) }
+
+#[inline]
+pub unsafe fn _mm256_add_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, i8::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm256_sub_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, i8::wrapping_sub) }
+
+#[inline]
+pub unsafe fn _mm256_adds_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, i8::saturating_add) }
+
+#[inline]
+pub unsafe fn _mm256_subs_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, i8::saturating_sub) }
+
+#[inline]
+pub unsafe fn _mm256_abs_epi8(a: __m256i) -> __m256i { cast(lanes::<_, i8, 32>(a).map(i8::wrapping_abs)) }
+
+#[inline]
+pub unsafe fn _mm256_sign_epi8(a: __m256i, b: __m256i) -> __m256i {
+    zip::<_, i8, 32>(a, b, |a, b| a.wrapping_mul(b.signum()))
+}
+
+#[inline]
+pub unsafe fn _mm256_shuffle_epi8(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, i8, 32>(a);
+    let b = lanes::<_, i8, 32>(b);
+    cast::<[i8; 32], _>(from_fn(|i| if b[i] < 0 { 0 } else { a[(i & 16) + (b[i] & 15) as usize] }))
+}
+
+#[inline]
+pub unsafe fn _mm256_permutevar8x32_epi32(a: __m256i, idx: __m256i) -> __m256i {
+    let a = lanes::<_, i32, 8>(a);
+    let idx = lanes::<_, i32, 8>(idx);
+    cast::<[i32; 8], _>(from_fn(|i| a[(idx[i] & 7) as usize]))
+}
+
+#[inline]
+pub unsafe fn _mm256_alignr_epi8<const IMM: i32>(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, u8, 32>(a);
+    let b = lanes::<_, u8, 32>(b);
+    cast::<[u8; 32], _>(from_fn(|i| {
+        // Each 128-bit half shifts the concatenation of the halves of `a` and `b` right by IMM bytes
+        let (half, j) = (i & 16, (i & 15) + IMM as usize);
+        match j {
+            0..=15 => b[half + j],
+            16..=31 => a[half + j - 16],
+            _ => 0,
+        }
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_permute2x128_si256<const IMM: i32>(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, i64, 4>(a);
+    let b = lanes::<_, i64, 4>(b);
+    let half = |sel: i32| -> [i64; 2] {
+        match sel & 0xb {
+            0 => [a[0], a[1]],
+            1 => [a[2], a[3]],
+            2 => [b[0], b[1]],
+            3 => [b[2], b[3]],
+            _ => [0; 2],
+        }
+    };
+    let (lo, hi) = (half(IMM), half(IMM >> 4));
+    __m256i([lo[0], lo[1], hi[0], hi[1]])
+}
+
+#[inline]
+pub unsafe fn _mm256_min_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_epi8(a: __m256i, b: __m256i) -> __m256i { zip::<_, i8, 32>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_min_epu8(a: __m256i, b: __m256i) -> __m256i { zip::<_, u8, 32>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_epu8(a: __m256i, b: __m256i) -> __m256i { zip::<_, u8, 32>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_add_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, i16::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm256_sub_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, i16::wrapping_sub) }
+
+#[inline]
+pub unsafe fn _mm256_mullo_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, i16::wrapping_mul) }
+
+#[inline]
+pub unsafe fn _mm256_madd_epi16(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, i16, 16>(a);
+    let b = lanes::<_, i16, 16>(b);
+    cast(from_fn::<i32, 8, _>(|i| {
+        (a[2 * i] as i32 * b[2 * i] as i32).wrapping_add(a[2 * i + 1] as i32 * b[2 * i + 1] as i32)
+    }))
+}
+
+#[inline]
+pub unsafe fn _mm256_sad_epu8(a: __m256i, b: __m256i) -> __m256i {
+    let a = lanes::<_, u8, 32>(a);
+    let b = lanes::<_, u8, 32>(b);
+    cast(from_fn::<u64, 4, _>(|i| (8 * i..8 * i + 8).map(|j| a[j].abs_diff(b[j]) as u64).sum()))
+}
+
+#[inline]
+pub unsafe fn _mm256_min_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_epi16(a: __m256i, b: __m256i) -> __m256i { zip::<_, i16, 16>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_min_epu16(a: __m256i, b: __m256i) -> __m256i { zip::<_, u16, 16>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_epu16(a: __m256i, b: __m256i) -> __m256i { zip::<_, u16, 16>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_add_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, i32::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm256_setzero_si256() -> __m256i { __m256i([0; 4]) }
+
+#[inline]
+pub unsafe fn _mm256_andnot_si256(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, |a, b| !a & b) }
+
+#[inline]
+pub unsafe fn _mm256_slli_epi16<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, u16, 16>(a).map(|v| v.checked_shl(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srli_epi16<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, u16, 16>(a).map(|v| v.checked_shr(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sll_epi16(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, u16, 16>(a).map(|v| v.checked_shl(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srl_epi16(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, u16, 16>(a).map(|v| v.checked_shr(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srai_epi16<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, i16, 16>(a).map(|v| v >> (IMM8 as u32).min(15)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sra_epi16(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, i16, 16>(a).map(|v| v >> shift_count(count).min(15)))
+}
+
+#[inline]
+pub unsafe fn _mm256_slli_epi32<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, u32, 8>(a).map(|v| v.checked_shl(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srli_epi32<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, u32, 8>(a).map(|v| v.checked_shr(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sll_epi32(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, u32, 8>(a).map(|v| v.checked_shl(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srl_epi32(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, u32, 8>(a).map(|v| v.checked_shr(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srai_epi32<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, i32, 8>(a).map(|v| v >> (IMM8 as u32).min(31)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sra_epi32(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, i32, 8>(a).map(|v| v >> shift_count(count).min(31)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sllv_epi32(a: __m256i, count: __m256i) -> __m256i {
+    zip::<_, u32, 8>(a, count, |v, c| if c < 32 { v << c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm256_srlv_epi32(a: __m256i, count: __m256i) -> __m256i {
+    zip::<_, u32, 8>(a, count, |v, c| if c < 32 { v >> c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm256_srav_epi32(a: __m256i, count: __m256i) -> __m256i {
+    zip::<_, i32, 8>(a, count, |v, c| v >> (c as u32).min(31))
+}
+
+#[inline]
+pub unsafe fn _mm256_slli_epi64<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, u64, 4>(a).map(|v| v.checked_shl(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srli_epi64<const IMM8: i32>(a: __m256i) -> __m256i {
+    cast(lanes::<_, u64, 4>(a).map(|v| v.checked_shr(IMM8 as u32).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sll_epi64(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, u64, 4>(a).map(|v| v.checked_shl(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_srl_epi64(a: __m256i, count: __m128i) -> __m256i {
+    cast(lanes::<_, u64, 4>(a).map(|v| v.checked_shr(shift_count(count)).unwrap_or(0)))
+}
+
+#[inline]
+pub unsafe fn _mm256_sllv_epi64(a: __m256i, count: __m256i) -> __m256i {
+    zip::<_, u64, 4>(a, count, |v, c| if c < 64 { v << c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm256_srlv_epi64(a: __m256i, count: __m256i) -> __m256i {
+    zip::<_, u64, 4>(a, count, |v, c| if c < 64 { v >> c } else { 0 })
+}
+
+#[inline]
+pub unsafe fn _mm256_sub_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, i32::wrapping_sub) }
+
+#[inline]
+pub unsafe fn _mm256_mullo_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, i32::wrapping_mul) }
+
+#[inline]
+pub unsafe fn _mm256_min_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_epi32(a: __m256i, b: __m256i) -> __m256i { zip::<_, i32, 8>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_min_epu32(a: __m256i, b: __m256i) -> __m256i { zip::<_, u32, 8>(a, b, min) }
+
+#[inline]
+pub unsafe fn _mm256_max_epu32(a: __m256i, b: __m256i) -> __m256i { zip::<_, u32, 8>(a, b, max) }
+
+#[inline]
+pub unsafe fn _mm256_add_epi64(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, i64::wrapping_add) }
+
+#[inline]
+pub unsafe fn _mm256_sub_epi64(a: __m256i, b: __m256i) -> __m256i { zip::<_, i64, 4>(a, b, i64::wrapping_sub) }
@@ -0,0 +1,20 @@
+//! Lane by lane scatter for CPUs without AVX-512F, which AVX2 has no instruction for.
+
+/// Writes `v[i]` to the address `a + idx[i] * scale` bytes for the lanes set in `mask`, in lane order so the last lane
+/// wins on duplicate indices like the native instruction.
+#[inline(always)]
+pub(crate) unsafe fn scatter<T: Copy, I: Into<i64>, const N: usize>(
+    a: *mut T,
+    v: [T; N],
+    idx: [I; N],
+    mask: u32,
+    scale: i32,
+) {
+    let a = a as *mut u8;
+
+    for (i, (v, idx)) in v.into_iter().zip(idx).enumerate() {
+        if mask >> i & 1 != 0 {
+            (a.offset((idx.into() * scale as i64) as isize) as *mut T).write_unaligned(v);
+        }
+    }
+}
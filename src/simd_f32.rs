@@ -1,13 +1,25 @@
-#[cfg(target_arch = "x86")]
-use std::arch::x86::*;
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
-
-use crate::{f64x4, i32x8};
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::{
+    aligned::is_aligned, arch::*, bounds::OutOfBounds, detect::assert_supported, f64x2, f64x4, f64x8, i32x16, i32x4,
+    i32x8, lanes::fmt_lanes, m32x16, m32x4, m32x8, partial::mask_epi32x4, partial::mask_epi32x8, reduce::first_lane,
+    scatter::scatter, swizzle::shift_left128, swizzle::shift_left256, swizzle::shift_right128, swizzle::shift_right256,
+    swizzle::shuffle128, swizzle::shuffle256, swizzle::swizzle128, swizzle::swizzle256, swizzle::Broadcast,
+    swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd, swizzle::InterleaveHi, swizzle::InterleaveLo,
+    swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight, swizzle::Swizzle,
+};
+#[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+use crate::{avx512, detect::has_avx512f};
+use std::{
+    array, fmt,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, Mul,
+        MulAssign, Neg, Not, Sub, SubAssign,
+    },
+    slice,
+};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct f32x8 {
     v: __m256,
 }
@@ -15,13 +27,17 @@ pub struct f32x8 {
 impl f32x8 {
     #[inline]
     pub fn new(v1: f32, v2: f32, v3: f32, v4: f32, v5: f32, v6: f32, v7: f32, v8: f32) -> Self {
+        assert_supported();
         Self {
             v: unsafe { _mm256_set_ps(v8, v7, v6, v5, v4, v3, v2, v1) },
         }
     }
 
     #[inline]
-    pub fn splat(v: f32) -> Self { unsafe { _mm256_set1_ps(v) }.into() }
+    pub fn splat(v: f32) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_ps(v) }.into()
+    }
 
     #[inline]
     pub fn permute<const IMM: i32>(self) -> Self { unsafe { _mm256_permute_ps::<IMM>(self.v) }.into() }
@@ -51,7 +67,70 @@ impl f32x8 {
     pub unsafe fn from_slice_unchecked(a: &[f32]) -> Self { Self::from_ptr(a.as_ptr()) }
 
     #[inline]
-    pub unsafe fn from_ptr(a: *const f32) -> Self { _mm256_loadu_ps(a).into() }
+    pub unsafe fn from_ptr(a: *const f32) -> Self {
+        assert_supported();
+        _mm256_loadu_ps(a).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[f32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[f32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const f32) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_ps(a).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[f32]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm256_maskload_ps(a.as_ptr(), mask_epi32x8(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[f32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const f32) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_castsi256_ps(_mm256_stream_load_si256(a as *const __m256i)).into()
+    }
 
     #[inline]
     pub unsafe fn gather_ptr<const SCALE: i32>(a: *const f32, idx: i32x8) -> Self {
@@ -63,6 +142,103 @@ impl f32x8 {
         Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
     }
 
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[f32], idx: i32x8) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<4>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[f32], idx: i32x8) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<4>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[f32], idx: i32x8, mask: m32x8, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<4>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const f32, idx: i32x8, mask: m32x8, default: Self) -> Self {
+        _mm256_mask_i32gather_ps::<SCALE>(default.v, a, idx.into(), _mm256_castsi256_ps(mask.into())).into()
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [f32], idx: i32x8) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [f32], idx: i32x8) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [f32], idx: i32x8, mask: m32x8) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<4>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut f32, idx: i32x8) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m32x8::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut f32, idx: i32x8, mask: m32x8) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i32scatter_ps_256::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        let mut v = [0.0; 8];
+        let mut i = [0; 8];
+        self.store(&mut v);
+        idx.store(&mut i);
+        scatter(a, v, i, mask.to_bitmask() as u32, SCALE)
+    }
+
     #[inline]
     pub fn to_raw_i32(self) -> i32x8 { unsafe { _mm256_castps_si256(self.v) }.into() }
 
@@ -72,6 +248,39 @@ impl f32x8 {
     #[inline]
     pub fn floor(self) -> f32x8 { unsafe { _mm256_floor_ps(self.v) }.into() }
 
+    #[inline]
+    pub fn ceil(self) -> Self { unsafe { _mm256_ceil_ps(self.v) }.into() }
+
+    /// Rounds half-way cases away from zero, like `f32::round`.
+    #[inline]
+    pub fn round(self) -> Self {
+        unsafe {
+            let sign = _mm256_set1_ps(-0.0);
+            let trunc = _mm256_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v);
+            let frac = _mm256_andnot_ps(sign, _mm256_sub_ps(self.v, trunc));
+            let away = _mm256_and_ps(_mm256_cmp_ps::<_CMP_GE_OQ>(frac, _mm256_set1_ps(0.5)), _mm256_set1_ps(1.0));
+            // Adding a signed zero keeps the sign of results that truncate to zero
+            _mm256_add_ps(trunc, _mm256_or_ps(away, _mm256_and_ps(self.v, sign)))
+        }
+        .into()
+    }
+
+    /// Rounds half-way cases to the even integer, like `f32::round_ties_even`.
+    #[inline]
+    pub fn round_ties_even(self) -> Self {
+        unsafe { _mm256_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self.v) }.into()
+    }
+
+    #[inline]
+    pub fn fract(self) -> Self {
+        unsafe { _mm256_sub_ps(self.v, _mm256_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v)) }.into()
+    }
+
+    /// Converts with the MXCSR rounding mode, to nearest with ties to even unless changed. Out of range lanes become
+    /// `i32::MIN`.
+    #[inline]
+    pub fn round_int(self) -> i32x8 { unsafe { _mm256_cvtps_epi32(self.v) }.into() }
+
     #[inline]
     pub fn to_raw_f64(self) -> f64x4 { unsafe { _mm256_castps_pd(self.v) }.into() }
 
@@ -95,6 +304,62 @@ impl f32x8 {
     #[inline]
     pub unsafe fn store_ptr(self, a: *mut f32) { _mm256_storeu_ps(a, self.v) }
 
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [f32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [f32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut f32) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_ps(a, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [f32]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm256_maskstore_ps(a.as_mut_ptr(), mask_epi32x8(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [f32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut f32) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_ps(a, self.v)
+    }
+
     #[inline]
     pub unsafe fn storeu2_ptr(self, hi: *mut f32, lo: *mut f32) { _mm256_storeu2_m128(hi, lo, self.v) }
 
@@ -104,6 +369,110 @@ impl f32x8 {
     #[inline]
     pub fn mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm256_fmadd_ps(self.v, mul.v, add.v) }.into() }
 
+    #[inline]
+    pub fn mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm256_fmsub_ps(self.v, mul.v, sub.v) }.into() }
+
+    #[inline]
+    pub fn neg_mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm256_fnmadd_ps(self.v, mul.v, add.v) }.into() }
+
+    #[inline]
+    pub fn neg_mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm256_fnmsub_ps(self.v, mul.v, sub.v) }.into() }
+
+    /// Subtracts `addsub` in even lanes and adds it in odd lanes.
+    #[inline]
+    pub fn mul_addsub(self, mul: Self, addsub: Self) -> Self {
+        unsafe { _mm256_fmaddsub_ps(self.v, mul.v, addsub.v) }.into()
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self { unsafe { _mm256_andnot_ps(_mm256_set1_ps(-0.0), self.v) }.into() }
+
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Self {
+        unsafe {
+            let mask = _mm256_set1_ps(-0.0);
+            _mm256_or_ps(_mm256_andnot_ps(mask, self.v), _mm256_and_ps(mask, sign.v))
+        }
+        .into()
+    }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_ps(other.v, self.v) }.into() }
+
+    /// Clears everything but the sign bit of each lane.
+    #[inline]
+    pub fn sign_bit(self) -> Self { unsafe { _mm256_and_ps(self.v, _mm256_set1_ps(-0.0)) }.into() }
+
+    /// The sign bit of each lane, lane 0 in the lowest bit.
+    #[inline]
+    pub fn sign_bitmask(self) -> u8 { unsafe { _mm256_movemask_ps(self.v) as u8 } }
+
+    /// `1.0` or `-1.0` with the sign of `self`, including for zeros, and NaN for NaN like `f32::signum`.
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let one = _mm256_or_ps(_mm256_and_ps(self.v, _mm256_set1_ps(-0.0)), _mm256_set1_ps(1.0));
+            _mm256_blendv_ps(one, self.v, _mm256_cmp_ps::<_CMP_UNORD_Q>(self.v, self.v))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn is_nan(self) -> m32x8 { self.compare::<_CMP_UNORD_Q>(self) }
+
+    #[inline]
+    pub fn is_finite(self) -> m32x8 { self.abs().lt(unsafe { _mm256_set1_ps(f32::INFINITY) }.into()) }
+
+    #[inline]
+    pub fn sqrt(self) -> Self { unsafe { _mm256_sqrt_ps(self.v) }.into() }
+
+    /// Approximate `1 / self` with a relative error of at most 1.5 * 2^-12.
+    #[inline]
+    pub fn rcp(self) -> Self { unsafe { _mm256_rcp_ps(self.v) }.into() }
+
+    /// `rcp` refined with one Newton-Raphson step, at most 2 ULP from `1 / self`.
+    #[inline]
+    pub fn rcp_nr(self) -> Self {
+        unsafe {
+            // The estimate flushes subnormal inputs and results to 0, so scale the inputs near either end of the range
+            let sign = _mm256_set1_ps(-0.0);
+            let abs = _mm256_andnot_ps(sign, self.v);
+            let huge = _mm256_cmp_ps::<_CMP_GT_OQ>(abs, _mm256_set1_ps(f32::MIN_POSITIVE.recip()));
+            let tiny = _mm256_cmp_ps::<_CMP_LT_OQ>(abs, _mm256_set1_ps(f32::MIN_POSITIVE));
+            let s = _mm256_blendv_ps(_mm256_set1_ps(1.0), _mm256_set1_ps(0.25), huge);
+            let s = _mm256_blendv_ps(s, _mm256_set1_ps(4.0), tiny);
+            let a = _mm256_mul_ps(self.v, s);
+            let x = _mm256_rcp_ps(a);
+            let e = _mm256_fnmadd_ps(a, x, _mm256_set1_ps(1.0));
+            // 0 and inf give a NaN error term and the smallest subnormals an infinite estimate, exact for all of them
+            let inf = _mm256_cmp_ps::<_CMP_EQ_OQ>(_mm256_andnot_ps(sign, x), _mm256_set1_ps(f32::INFINITY));
+            let exact = _mm256_or_ps(_mm256_cmp_ps::<_CMP_UNORD_Q>(e, e), inf);
+            _mm256_mul_ps(_mm256_blendv_ps(_mm256_fmadd_ps(x, e, x), x, exact), s)
+        }
+        .into()
+    }
+
+    /// Approximate `1 / sqrt(self)` with a relative error of at most 1.5 * 2^-12.
+    #[inline]
+    pub fn rsqrt(self) -> Self { unsafe { _mm256_rsqrt_ps(self.v) }.into() }
+
+    /// `rsqrt` refined with one Newton-Raphson step, at most 4 ULP from `1 / sqrt(self)`.
+    #[inline]
+    pub fn rsqrt_nr(self) -> Self {
+        unsafe {
+            // The estimate flushes subnormal inputs to 0, so scale them by 2^24 and the result back by 2^12
+            let tiny = _mm256_cmp_ps::<_CMP_LT_OQ>(self.v, _mm256_set1_ps(f32::MIN_POSITIVE));
+            let a = _mm256_blendv_ps(self.v, _mm256_mul_ps(self.v, _mm256_set1_ps(16777216.0)), tiny);
+            let half = _mm256_set1_ps(0.5);
+            let y = _mm256_rsqrt_ps(a);
+            let e = _mm256_fnmadd_ps(_mm256_mul_ps(_mm256_mul_ps(a, half), y), y, half);
+            let y = _mm256_blendv_ps(_mm256_fmadd_ps(y, e, y), y, _mm256_cmp_ps::<_CMP_UNORD_Q>(e, e));
+            _mm256_mul_ps(y, _mm256_blendv_ps(_mm256_set1_ps(1.0), _mm256_set1_ps(4096.0), tiny))
+        }
+        .into()
+    }
+
     #[inline]
     pub fn min(self, other: Self) -> Self { unsafe { _mm256_min_ps(self.v, other.v) }.into() }
 
@@ -124,52 +493,1598 @@ impl f32x8 {
         }
     }
 
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128, __m128) -> __m128) -> f32 {
+        unsafe {
+            let v = op(_mm256_castps256_ps128(self.v), _mm256_extractf128_ps::<1>(self.v));
+            let v = op(v, _mm_movehl_ps(v, v));
+            _mm_cvtss_f32(op(v, _mm_shuffle_ps::<1>(v, v)))
+        }
+    }
+
+    #[inline]
+    pub fn product(self) -> f32 { self.reduce(|a, b| unsafe { _mm_mul_ps(a, b) }) }
+
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_min(self) -> f32 {
+        self.reduce(|a, b| unsafe { _mm_blendv_ps(_mm_min_ps(a, b), a, _mm_cmp_ps::<_CMP_UNORD_Q>(b, b)) })
+    }
+
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_max(self) -> f32 {
+        self.reduce(|a, b| unsafe { _mm_blendv_ps(_mm_max_ps(a, b), a, _mm_cmp_ps::<_CMP_UNORD_Q>(b, b)) })
+    }
+
+    /// Index of the first lane holding the minimum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    fn compare<const IMM: i32>(self, other: Self) -> m32x8 {
+        unsafe { _mm256_castps_si256(_mm256_cmp_ps::<IMM>(self.v, other.v)) }.into()
+    }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x8 { self.compare::<_CMP_EQ_OQ>(other) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x8 { self.compare::<_CMP_NEQ_UQ>(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x8 { self.compare::<_CMP_LT_OQ>(other) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x8 { self.compare::<_CMP_LE_OQ>(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x8 { self.compare::<_CMP_GT_OQ>(other) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x8 { self.compare::<_CMP_GE_OQ>(other) }
+
+    #[inline]
+    pub fn select(mask: m32x8, a: Self, b: Self) -> Self {
+        unsafe { _mm256_blendv_ps(b.v, a.v, _mm256_castsi256_ps(mask.into())) }.into()
+    }
+
+    #[inline]
+    pub fn lo(self) -> f32x4 { unsafe { _mm256_castps256_ps128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> f32x4 { unsafe { _mm256_extractf128_ps::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: f32x4, hi: f32x4) -> Self { unsafe { _mm256_set_m128(hi.into(), lo.into()) }.into() }
+
     #[inline]
     pub fn extract<const INDEX: i32>(self) -> f32 { f32::from_bits(self.to_raw_i32().extract::<INDEX>() as u32) }
-}
 
-impl From<__m256> for f32x8 {
     #[inline]
-    fn from(v: __m256) -> Self { Self { v } }
-}
+    pub fn to_array(self) -> [f32; 8] { *self.as_array() }
 
-impl From<f32x8> for __m256 {
+    /// The lanes in the order of the [`new`](Self::new) arguments.
     #[inline]
-    fn from(v: f32x8) -> Self { v.v }
-}
+    pub fn as_array(&self) -> &[f32; 8] { unsafe { &*(self as *const Self as *const [f32; 8]) } }
 
-impl Mul<f32x8> for f32x8 {
-    type Output = f32x8;
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> f32 { self.as_array()[i] }
 
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
     #[inline]
-    fn mul(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_mul_ps(self.v, rhs.v) }.into() }
-}
+    pub fn replace_lane(self, i: usize, v: f32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
 
-impl MulAssign<f32x8> for f32x8 {
     #[inline]
-    fn mul_assign(&mut self, rhs: f32x8) { self.v = (*self * rhs).v }
-}
+    pub fn iter(&self) -> slice::Iter<'_, f32> { self.as_array().iter() }
 
-impl Add<f32x8> for f32x8 {
-    type Output = f32x8;
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> f32>(f: F) -> Self { Self::from(array::from_fn(f)) }
 
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
     #[inline]
-    fn add(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_add_ps(self.v, rhs.v) }.into() }
-}
+    pub fn swizzle<S: Swizzle>(self) -> Self {
+        unsafe { _mm256_castsi256_ps(swizzle256::<S, 8>(_mm256_castps_si256(self.v))) }.into()
+    }
 
-impl AddAssign for f32x8 {
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
     #[inline]
-    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
-}
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        unsafe { _mm256_castsi256_ps(shuffle256::<S, 8>(_mm256_castps_si256(self.v), _mm256_castps_si256(other.v))) }
+            .into()
+    }
 
-impl Sub<f32x8> for f32x8 {
-    type Output = f32x8;
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<8>>() }
 
+    /// Lane `(i + N) % 8` for each lane `i`.
     #[inline]
-    fn sub(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_sub_ps(self.v, rhs.v) }.into() }
-}
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<8, N>>() }
 
-impl SubAssign for f32x8 {
+    /// Lane `(i - N) % 8` for each lane `i`.
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<8, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm256_castsi256_ps(shift_left256::<8, N>(_mm256_castps_si256(self.v), _mm256_castps_si256(fill.v))) }
+            .into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm256_castsi256_ps(shift_right256::<8, N>(_mm256_castps_si256(self.v), _mm256_castps_si256(fill.v))) }
+            .into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<8, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<8>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<8>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<8>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<8>>(other) }
+}
+
+impl Default for f32x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0.0) }
+}
+
+impl PartialEq for f32x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { f32x8::eq(*self, *other).all() }
+}
+
+impl From<[f32; 8]> for f32x8 {
+    #[inline]
+    fn from(a: [f32; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<f32x8> for [f32; 8] {
+    #[inline]
+    fn from(v: f32x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for f32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("f32x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for f32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for f32x8 {
+    type Output = f32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &f32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for f32x8 {
+    type Item = f32;
+    type IntoIter = array::IntoIter<f32, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a f32x8 {
+    type Item = &'a f32;
+    type IntoIter = slice::Iter<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256> for f32x8 {
+    #[inline]
+    fn from(v: __m256) -> Self { Self { v } }
+}
+
+impl From<f32x8> for __m256 {
+    #[inline]
+    fn from(v: f32x8) -> Self { v.v }
+}
+
+impl Mul<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn mul(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_mul_ps(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<f32x8> for f32x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32x8) { self.v = (*self * rhs).v }
+}
+
+impl Add<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn add(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_add_ps(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for f32x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn sub(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_sub_ps(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for f32x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl Div<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn div(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_div_ps(self.v, rhs.v) }.into() }
+}
+
+impl DivAssign for f32x8 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) { self.v = (*self / rhs).v }
+}
+
+impl Neg for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn neg(self) -> Self::Output { unsafe { _mm256_xor_ps(self.v, _mm256_set1_ps(-0.0)) }.into() }
+}
+
+impl BitAnd<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn bitand(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_and_ps(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for f32x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn bitor(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_or_ps(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for f32x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<f32x8> for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn bitxor(self, rhs: f32x8) -> Self::Output { unsafe { _mm256_xor_ps(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for f32x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for f32x8 {
+    type Output = f32x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_ps(self.v, _mm256_castsi256_ps(_mm256_set1_epi32(-1))) }.into() }
+}
+
+/// The 128-bit counterpart of [`f32x8`]. Gathers, scatters, `permute4x64`, `storeu2_ptr` and `lo`/`hi`/`concat` are
+/// 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct f32x4 {
+    v: __m128,
+}
+
+impl f32x4 {
+    #[inline]
+    pub fn new(v1: f32, v2: f32, v3: f32, v4: f32) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm_set_ps(v4, v3, v2, v1) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> f32x4 {
+        assert_supported();
+        unsafe { _mm_set1_ps(v) }.into()
+    }
+
+    #[inline]
+    pub fn permute<const IMM: i32>(self) -> Self { unsafe { _mm_permute_ps::<IMM>(self.v) }.into() }
+
+    #[inline]
+    pub fn permute_var(self, idx: i32x4) -> Self { unsafe { _mm_permutevar_ps(self.v, idx.into()) }.into() }
+
+    #[inline]
+    pub fn unpacklo(self, b: f32x4) -> Self { unsafe { _mm_unpacklo_ps(self.v, b.v) }.into() }
+
+    #[inline]
+    pub fn unpackhi(self, b: f32x4) -> Self { unsafe { _mm_unpackhi_ps(self.v, b.v) }.into() }
+
+    #[inline]
+    pub fn from_slice(a: &[f32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[f32]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const f32) -> Self {
+        assert_supported();
+        _mm_loadu_ps(a).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[f32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[f32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const f32) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_ps(a).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 4 and zeroes the rest, or 4 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[f32]) -> Self {
+        if a.len() >= 4 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm_maskload_ps(a.as_ptr(), mask_epi32x4(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[f32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const f32) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_castsi128_ps(_mm_stream_load_si128(a as *const __m128i)).into()
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x4 { unsafe { _mm_castps_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn trunc(self) -> i32x4 { unsafe { _mm_cvttps_epi32(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_f64(self) -> f64x2 { unsafe { _mm_castps_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn to_f64(self) -> f64x4 { unsafe { _mm256_cvtps_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [f32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [f32]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut f32) { _mm_storeu_ps(a, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [f32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [f32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut f32) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_ps(a, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 4, or 4 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [f32]) {
+        if a.len() >= 4 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm_maskstore_ps(a.as_mut_ptr(), mask_epi32x4(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [f32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut f32) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_ps(a, self.v)
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self { unsafe { _mm_floor_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn ceil(self) -> Self { unsafe { _mm_ceil_ps(self.v) }.into() }
+
+    /// Rounds half-way cases away from zero, like `f32::round`.
+    #[inline]
+    pub fn round(self) -> Self {
+        unsafe {
+            let sign = _mm_set1_ps(-0.0);
+            let trunc = _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v);
+            let frac = _mm_andnot_ps(sign, _mm_sub_ps(self.v, trunc));
+            let away = _mm_and_ps(_mm_cmp_ps::<_CMP_GE_OQ>(frac, _mm_set1_ps(0.5)), _mm_set1_ps(1.0));
+            // Adding a signed zero keeps the sign of results that truncate to zero
+            _mm_add_ps(trunc, _mm_or_ps(away, _mm_and_ps(self.v, sign)))
+        }
+        .into()
+    }
+
+    /// Rounds half-way cases to the even integer, like `f32::round_ties_even`.
+    #[inline]
+    pub fn round_ties_even(self) -> Self {
+        unsafe { _mm_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self.v) }.into()
+    }
+
+    #[inline]
+    pub fn fract(self) -> Self {
+        unsafe { _mm_sub_ps(self.v, _mm_round_ps::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v)) }.into()
+    }
+
+    /// Converts with the MXCSR rounding mode, to nearest with ties to even unless changed. Out of range lanes become
+    /// `i32::MIN`.
+    #[inline]
+    pub fn round_int(self) -> i32x4 { unsafe { _mm_cvtps_epi32(self.v) }.into() }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self { unsafe { _mm_blend_ps::<IMM>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn hadd(self, other: Self) -> Self { unsafe { _mm_hadd_ps(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm_fmadd_ps(self.v, mul.v, add.v) }.into() }
+
+    #[inline]
+    pub fn mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm_fmsub_ps(self.v, mul.v, sub.v) }.into() }
+
+    #[inline]
+    pub fn neg_mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm_fnmadd_ps(self.v, mul.v, add.v) }.into() }
+
+    #[inline]
+    pub fn neg_mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm_fnmsub_ps(self.v, mul.v, sub.v) }.into() }
+
+    /// Subtracts `addsub` in even lanes and adds it in odd lanes.
+    #[inline]
+    pub fn mul_addsub(self, mul: Self, addsub: Self) -> Self {
+        unsafe { _mm_fmaddsub_ps(self.v, mul.v, addsub.v) }.into()
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self { unsafe { _mm_andnot_ps(_mm_set1_ps(-0.0), self.v) }.into() }
+
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Self {
+        unsafe {
+            let mask = _mm_set1_ps(-0.0);
+            _mm_or_ps(_mm_andnot_ps(mask, self.v), _mm_and_ps(mask, sign.v))
+        }
+        .into()
+    }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_ps(other.v, self.v) }.into() }
+
+    /// Clears everything but the sign bit of each lane.
+    #[inline]
+    pub fn sign_bit(self) -> Self { unsafe { _mm_and_ps(self.v, _mm_set1_ps(-0.0)) }.into() }
+
+    /// The sign bit of each lane, lane 0 in the lowest bit.
+    #[inline]
+    pub fn sign_bitmask(self) -> u8 { unsafe { _mm_movemask_ps(self.v) as u8 } }
+
+    /// `1.0` or `-1.0` with the sign of `self`, including for zeros, and NaN for NaN like `f32::signum`.
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let one = _mm_or_ps(_mm_and_ps(self.v, _mm_set1_ps(-0.0)), _mm_set1_ps(1.0));
+            _mm_blendv_ps(one, self.v, _mm_cmp_ps::<_CMP_UNORD_Q>(self.v, self.v))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn is_nan(self) -> m32x4 { self.compare::<_CMP_UNORD_Q>(self) }
+
+    #[inline]
+    pub fn is_finite(self) -> m32x4 { self.abs().lt(unsafe { _mm_set1_ps(f32::INFINITY) }.into()) }
+
+    #[inline]
+    pub fn sqrt(self) -> Self { unsafe { _mm_sqrt_ps(self.v) }.into() }
+
+    /// Approximate `1 / self` with a relative error of at most 1.5 * 2^-12.
+    #[inline]
+    pub fn rcp(self) -> Self { unsafe { _mm_rcp_ps(self.v) }.into() }
+
+    /// `rcp` refined with one Newton-Raphson step, at most 2 ULP from `1 / self`.
+    #[inline]
+    pub fn rcp_nr(self) -> Self {
+        unsafe {
+            // The estimate flushes subnormal inputs and results to 0, so scale the inputs near either end of the range
+            let sign = _mm_set1_ps(-0.0);
+            let abs = _mm_andnot_ps(sign, self.v);
+            let huge = _mm_cmp_ps::<_CMP_GT_OQ>(abs, _mm_set1_ps(f32::MIN_POSITIVE.recip()));
+            let tiny = _mm_cmp_ps::<_CMP_LT_OQ>(abs, _mm_set1_ps(f32::MIN_POSITIVE));
+            let s = _mm_blendv_ps(_mm_set1_ps(1.0), _mm_set1_ps(0.25), huge);
+            let s = _mm_blendv_ps(s, _mm_set1_ps(4.0), tiny);
+            let a = _mm_mul_ps(self.v, s);
+            let x = _mm_rcp_ps(a);
+            let e = _mm_fnmadd_ps(a, x, _mm_set1_ps(1.0));
+            // 0 and inf give a NaN error term and the smallest subnormals an infinite estimate, exact for all of them
+            let inf = _mm_cmp_ps::<_CMP_EQ_OQ>(_mm_andnot_ps(sign, x), _mm_set1_ps(f32::INFINITY));
+            let exact = _mm_or_ps(_mm_cmp_ps::<_CMP_UNORD_Q>(e, e), inf);
+            _mm_mul_ps(_mm_blendv_ps(_mm_fmadd_ps(x, e, x), x, exact), s)
+        }
+        .into()
+    }
+
+    /// Approximate `1 / sqrt(self)` with a relative error of at most 1.5 * 2^-12.
+    #[inline]
+    pub fn rsqrt(self) -> Self { unsafe { _mm_rsqrt_ps(self.v) }.into() }
+
+    /// `rsqrt` refined with one Newton-Raphson step, at most 4 ULP from `1 / sqrt(self)`.
+    #[inline]
+    pub fn rsqrt_nr(self) -> Self {
+        unsafe {
+            // The estimate flushes subnormal inputs to 0, so scale them by 2^24 and the result back by 2^12
+            let tiny = _mm_cmp_ps::<_CMP_LT_OQ>(self.v, _mm_set1_ps(f32::MIN_POSITIVE));
+            let a = _mm_blendv_ps(self.v, _mm_mul_ps(self.v, _mm_set1_ps(16777216.0)), tiny);
+            let half = _mm_set1_ps(0.5);
+            let y = _mm_rsqrt_ps(a);
+            let e = _mm_fnmadd_ps(_mm_mul_ps(_mm_mul_ps(a, half), y), y, half);
+            let y = _mm_blendv_ps(_mm_fmadd_ps(y, e, y), y, _mm_cmp_ps::<_CMP_UNORD_Q>(e, e));
+            _mm_mul_ps(y, _mm_blendv_ps(_mm_set1_ps(1.0), _mm_set1_ps(4096.0), tiny))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn sum(self) -> f32 { self.reduce(|a, b| unsafe { _mm_add_ps(a, b) }) }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128, __m128) -> __m128) -> f32 {
+        unsafe {
+            let v = op(self.v, _mm_movehl_ps(self.v, self.v));
+            _mm_cvtss_f32(op(v, _mm_shuffle_ps::<1>(v, v)))
+        }
+    }
+
+    #[inline]
+    pub fn product(self) -> f32 { self.reduce(|a, b| unsafe { _mm_mul_ps(a, b) }) }
+
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_min(self) -> f32 {
+        self.reduce(|a, b| unsafe { _mm_blendv_ps(_mm_min_ps(a, b), a, _mm_cmp_ps::<_CMP_UNORD_Q>(b, b)) })
+    }
+
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_max(self) -> f32 {
+        self.reduce(|a, b| unsafe { _mm_blendv_ps(_mm_max_ps(a, b), a, _mm_cmp_ps::<_CMP_UNORD_Q>(b, b)) })
+    }
+
+    /// Index of the first lane holding the minimum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm_min_ps(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm_max_ps(self.v, other.v) }.into() }
+
+    #[inline]
+    fn compare<const IMM: i32>(self, other: Self) -> m32x4 {
+        unsafe { _mm_castps_si128(_mm_cmp_ps::<IMM>(self.v, other.v)) }.into()
+    }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x4 { self.compare::<_CMP_EQ_OQ>(other) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x4 { self.compare::<_CMP_NEQ_UQ>(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x4 { self.compare::<_CMP_LT_OQ>(other) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x4 { self.compare::<_CMP_LE_OQ>(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x4 { self.compare::<_CMP_GT_OQ>(other) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x4 { self.compare::<_CMP_GE_OQ>(other) }
+
+    #[inline]
+    pub fn select(mask: m32x4, a: Self, b: Self) -> Self {
+        unsafe { _mm_blendv_ps(b.v, a.v, _mm_castsi128_ps(mask.into())) }.into()
+    }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> f32 { f32::from_bits(self.to_raw_i32().extract::<INDEX>() as u32) }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 4] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[f32; 4] { unsafe { &*(self as *const Self as *const [f32; 4]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> f32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: f32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> f32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self {
+        unsafe { _mm_castsi128_ps(swizzle128::<S, 4>(_mm_castps_si128(self.v))) }.into()
+    }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        unsafe { _mm_castsi128_ps(shuffle128::<S, 4>(_mm_castps_si128(self.v), _mm_castps_si128(other.v))) }.into()
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<4>>() }
+
+    /// Lane `(i + N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<4, N>>() }
+
+    /// Lane `(i - N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<4, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm_castsi128_ps(shift_left128::<4, N>(_mm_castps_si128(self.v), _mm_castps_si128(fill.v))) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm_castsi128_ps(shift_right128::<4, N>(_mm_castps_si128(self.v), _mm_castps_si128(fill.v))) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<4, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<4>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<4>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<4>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<4>>(other) }
+}
+
+impl Default for f32x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(0.0) }
+}
+
+impl PartialEq for f32x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { f32x4::eq(*self, *other).all() }
+}
+
+impl From<[f32; 4]> for f32x4 {
+    #[inline]
+    fn from(a: [f32; 4]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<f32x4> for [f32; 4] {
+    #[inline]
+    fn from(v: f32x4) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for f32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("f32x4").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for f32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for f32x4 {
+    type Output = f32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &f32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for f32x4 {
+    type Item = f32;
+    type IntoIter = array::IntoIter<f32, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a f32x4 {
+    type Item = &'a f32;
+    type IntoIter = slice::Iter<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128> for f32x4 {
+    #[inline]
+    fn from(v: __m128) -> Self { Self { v } }
+}
+
+impl From<f32x4> for __m128 {
+    #[inline]
+    fn from(v: f32x4) -> Self { v.v }
+}
+
+impl Mul<f32x4> for f32x4 {
+    type Output = f32x4;
+
+    #[inline]
+    fn mul(self, rhs: f32x4) -> Self::Output { unsafe { _mm_mul_ps(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<f32x4> for f32x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32x4) { self.v = (*self * rhs).v }
+}
+
+impl Add<f32x4> for f32x4 {
+    type Output = f32x4;
+
+    #[inline]
+    fn add(self, rhs: f32x4) -> Self::Output { unsafe { _mm_add_ps(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for f32x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<f32x4> for f32x4 {
+    type Output = f32x4;
+
+    #[inline]
+    fn sub(self, rhs: f32x4) -> Self::Output { unsafe { _mm_sub_ps(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for f32x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl Div<f32x4> for f32x4 {
+    type Output = f32x4;
+
+    #[inline]
+    fn div(self, rhs: f32x4) -> Self::Output { unsafe { _mm_div_ps(self.v, rhs.v) }.into() }
+}
+
+impl DivAssign for f32x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f32x4) { self.v = (*self / rhs).v }
+}
+
+impl Neg for f32x4 {
+    type Output = f32x4;
+
+    #[inline]
+    fn neg(self) -> Self::Output { unsafe { _mm_xor_ps(self.v, _mm_set1_ps(-0.0)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct f32x16 {
+    lo: f32x8,
+    hi: f32x8,
+}
+
+impl f32x16 {
+    #[inline]
+    pub fn new(
+        v1: f32,
+        v2: f32,
+        v3: f32,
+        v4: f32,
+        v5: f32,
+        v6: f32,
+        v7: f32,
+        v8: f32,
+        v9: f32,
+        v10: f32,
+        v11: f32,
+        v12: f32,
+        v13: f32,
+        v14: f32,
+        v15: f32,
+        v16: f32,
+    ) -> Self {
+        Self::concat(f32x8::new(v1, v2, v3, v4, v5, v6, v7, v8), f32x8::new(v9, v10, v11, v12, v13, v14, v15, v16))
+    }
+
+    #[inline]
+    pub fn splat(v: f32) -> Self { Self::concat(f32x8::splat(v), f32x8::splat(v)) }
+
+    #[inline]
+    pub fn from_slice(a: &[f32]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[f32]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const f32) -> Self { Self::concat(f32x8::from_ptr(a), f32x8::from_ptr(a.add(8))) }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[f32]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[f32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const f32) -> Self {
+        Self::concat(f32x8::from_ptr_aligned(a), f32x8::from_ptr_aligned(a.add(8)))
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 16 and zeroes the rest, or 16 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[f32]) -> Self {
+        if a.len() >= 16 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at(a.len().min(8));
+        Self::concat(f32x8::load_partial(lo), f32x8::load_partial(hi))
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[f32]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const f32) -> Self {
+        Self::concat(f32x8::from_ptr_stream(a), f32x8::from_ptr_stream(a.add(8)))
+    }
+
+    #[inline]
+    pub fn permute<const IMM: i32>(self) -> Self { Self::concat(self.lo.permute::<IMM>(), self.hi.permute::<IMM>()) }
+
+    #[inline]
+    pub fn permute4x64<const IMM: i32>(self) -> Self {
+        Self::concat(self.lo.permute4x64::<IMM>(), self.hi.permute4x64::<IMM>())
+    }
+
+    #[inline]
+    pub fn permute_var(self, idx: i32x16) -> Self {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return unsafe { avx512::permutexvar_ps(self, idx) };
+        }
+
+        Self::concat(self.permute_half(idx.lo()), self.permute_half(idx.hi()))
+    }
+
+    #[inline]
+    fn permute_half(self, idx: i32x8) -> f32x8 {
+        let (lo, hi) = (self.lo.permute_var(idx), self.hi.permute_var(idx));
+        unsafe {
+            // Bit 3 of the index picks the half, shifted into the sign bit for blendv
+            let from_hi = _mm256_castsi256_ps(_mm256_slli_epi32::<28>(idx.into()));
+            _mm256_blendv_ps(lo.into(), hi.into(), from_hi)
+        }
+        .into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_ptr<const SCALE: i32>(a: *const f32, idx: i32x16) -> Self {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::i32gather_ps::<SCALE>(a, idx);
+        }
+
+        Self::concat(f32x8::gather_ptr::<SCALE>(a, idx.lo()), f32x8::gather_ptr::<SCALE>(a, idx.hi()))
+    }
+
+    #[inline]
+    pub unsafe fn gather_unchecked<const SCALE: i32>(a: &[f32], idx: i32x16) -> Self {
+        Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[f32], idx: i32x16) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<4>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[f32], idx: i32x16) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<4>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[f32], idx: i32x16, mask: m32x16, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<4>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const f32, idx: i32x16, mask: m32x16, default: Self) -> Self {
+        Self::concat(
+            f32x8::gather_masked_ptr::<SCALE>(a, idx.lo(), mask.lo(), default.lo),
+            f32x8::gather_masked_ptr::<SCALE>(a, idx.hi(), mask.hi(), default.hi),
+        )
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [f32], idx: i32x16) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [f32], idx: i32x16) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [f32], idx: i32x16, mask: m32x16) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<4>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut f32, idx: i32x16) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m32x16::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut f32, idx: i32x16, mask: m32x16) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i32scatter_ps::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        self.lo.scatter_masked_ptr::<SCALE>(a, idx.lo(), mask.lo());
+        self.hi.scatter_masked_ptr::<SCALE>(a, idx.hi(), mask.hi())
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x16 { i32x16::concat(self.lo.to_raw_i32(), self.hi.to_raw_i32()) }
+
+    #[inline]
+    pub fn to_raw_f64(self) -> f64x8 { f64x8::concat(self.lo.to_raw_f64(), self.hi.to_raw_f64()) }
+
+    #[inline]
+    pub fn trunc(self) -> i32x16 { i32x16::concat(self.lo.trunc(), self.hi.trunc()) }
+
+    #[inline]
+    pub fn floor(self) -> Self { Self::concat(self.lo.floor(), self.hi.floor()) }
+
+    #[inline]
+    pub fn ceil(self) -> Self { Self::concat(self.lo.ceil(), self.hi.ceil()) }
+
+    #[inline]
+    pub fn round(self) -> Self { Self::concat(self.lo.round(), self.hi.round()) }
+
+    #[inline]
+    pub fn round_ties_even(self) -> Self { Self::concat(self.lo.round_ties_even(), self.hi.round_ties_even()) }
+
+    #[inline]
+    pub fn fract(self) -> Self { Self::concat(self.lo.fract(), self.hi.fract()) }
+
+    #[inline]
+    pub fn round_int(self) -> i32x16 { i32x16::concat(self.lo.round_int(), self.hi.round_int()) }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        Self::select(m32x16::from_bitmask(IMM as u16), other, self)
+    }
+
+    #[inline]
+    pub fn store(self, a: &mut [f32]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [f32]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut f32) {
+        self.lo.store_ptr(a);
+        self.hi.store_ptr(a.add(8));
+    }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [f32]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [f32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut f32) {
+        self.lo.store_ptr_aligned(a);
+        self.hi.store_ptr_aligned(a.add(8));
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 16, or 16 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [f32]) {
+        if a.len() >= 16 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at_mut(a.len().min(8));
+        self.lo.store_partial(lo);
+        self.hi.store_partial(hi);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [f32]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut f32) {
+        self.lo.store_ptr_stream(a);
+        self.hi.store_ptr_stream(a.add(8));
+    }
+
+    #[inline]
+    pub fn mul_add(self, mul: Self, add: Self) -> Self {
+        Self::concat(self.lo.mul_add(mul.lo, add.lo), self.hi.mul_add(mul.hi, add.hi))
+    }
+
+    #[inline]
+    pub fn mul_sub(self, mul: Self, sub: Self) -> Self {
+        Self::concat(self.lo.mul_sub(mul.lo, sub.lo), self.hi.mul_sub(mul.hi, sub.hi))
+    }
+
+    #[inline]
+    pub fn neg_mul_add(self, mul: Self, add: Self) -> Self {
+        Self::concat(self.lo.neg_mul_add(mul.lo, add.lo), self.hi.neg_mul_add(mul.hi, add.hi))
+    }
+
+    #[inline]
+    pub fn neg_mul_sub(self, mul: Self, sub: Self) -> Self {
+        Self::concat(self.lo.neg_mul_sub(mul.lo, sub.lo), self.hi.neg_mul_sub(mul.hi, sub.hi))
+    }
+
+    #[inline]
+    pub fn mul_addsub(self, mul: Self, addsub: Self) -> Self {
+        Self::concat(self.lo.mul_addsub(mul.lo, addsub.lo), self.hi.mul_addsub(mul.hi, addsub.hi))
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self { Self::concat(self.lo.abs(), self.hi.abs()) }
+
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Self { Self::concat(self.lo.copysign(sign.lo), self.hi.copysign(sign.hi)) }
+
+    #[inline]
+    pub fn signum(self) -> Self { Self::concat(self.lo.signum(), self.hi.signum()) }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { Self::concat(self.lo.andnot(other.lo), self.hi.andnot(other.hi)) }
+
+    #[inline]
+    pub fn sign_bit(self) -> Self { Self::concat(self.lo.sign_bit(), self.hi.sign_bit()) }
+
+    #[inline]
+    pub fn sign_bitmask(self) -> u16 { self.lo.sign_bitmask() as u16 | (self.hi.sign_bitmask() as u16) << 8 }
+
+    #[inline]
+    pub fn is_nan(self) -> m32x16 { m32x16::concat(self.lo.is_nan(), self.hi.is_nan()) }
+
+    #[inline]
+    pub fn is_finite(self) -> m32x16 { m32x16::concat(self.lo.is_finite(), self.hi.is_finite()) }
+
+    #[inline]
+    pub fn sqrt(self) -> Self { Self::concat(self.lo.sqrt(), self.hi.sqrt()) }
+
+    #[inline]
+    pub fn rcp(self) -> Self { Self::concat(self.lo.rcp(), self.hi.rcp()) }
+
+    #[inline]
+    pub fn rcp_nr(self) -> Self { Self::concat(self.lo.rcp_nr(), self.hi.rcp_nr()) }
+
+    #[inline]
+    pub fn rsqrt(self) -> Self { Self::concat(self.lo.rsqrt(), self.hi.rsqrt()) }
+
+    #[inline]
+    pub fn rsqrt_nr(self) -> Self { Self::concat(self.lo.rsqrt_nr(), self.hi.rsqrt_nr()) }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::concat(self.lo.min(other.lo), self.hi.min(other.hi)) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::concat(self.lo.max(other.lo), self.hi.max(other.hi)) }
+
+    #[inline]
+    pub fn sum(self) -> f32 { (self.lo + self.hi).sum() }
+
+    #[inline]
+    pub fn product(self) -> f32 { (self.lo * self.hi).product() }
+
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_min(self) -> f32 { self.lo.reduce_min().min(self.hi.reduce_min()) }
+
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_max(self) -> f32 { self.lo.reduce_max().max(self.hi.reduce_max()) }
+
+    /// Index of the first lane holding the minimum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x16 { m32x16::concat(self.lo.eq(other.lo), self.hi.eq(other.hi)) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x16 { m32x16::concat(self.lo.ne(other.lo), self.hi.ne(other.hi)) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x16 { m32x16::concat(self.lo.lt(other.lo), self.hi.lt(other.hi)) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x16 { m32x16::concat(self.lo.le(other.lo), self.hi.le(other.hi)) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x16 { m32x16::concat(self.lo.gt(other.lo), self.hi.gt(other.hi)) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x16 { m32x16::concat(self.lo.ge(other.lo), self.hi.ge(other.hi)) }
+
+    #[inline]
+    pub fn select(mask: m32x16, a: Self, b: Self) -> Self {
+        Self::concat(f32x8::select(mask.lo(), a.lo, b.lo), f32x8::select(mask.hi(), a.hi, b.hi))
+    }
+
+    #[inline]
+    pub fn lo(self) -> f32x8 { self.lo }
+
+    #[inline]
+    pub fn hi(self) -> f32x8 { self.hi }
+
+    #[inline]
+    pub fn concat(lo: f32x8, hi: f32x8) -> Self { Self { lo, hi } }
+
+    #[inline]
+    pub fn to_array(self) -> [f32; 16] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[f32; 16] { unsafe { &*(self as *const Self as *const [f32; 16]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> f32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: f32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> f32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { self.to_raw_i32().swizzle::<S>().to_raw_f32() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        self.to_raw_i32().shuffle2::<S>(other.to_raw_i32()).to_raw_f32()
+    }
+}
+
+impl Default for f32x16 {
+    #[inline]
+    fn default() -> Self { Self::splat(0.0) }
+}
+
+impl PartialEq for f32x16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { f32x16::eq(*self, *other).all() }
+}
+
+impl From<[f32; 16]> for f32x16 {
+    #[inline]
+    fn from(a: [f32; 16]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<f32x16> for [f32; 16] {
+    #[inline]
+    fn from(v: f32x16) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for f32x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("f32x16").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for f32x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for f32x16 {
+    type Output = f32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &f32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for f32x16 {
+    type Item = f32;
+    type IntoIter = array::IntoIter<f32, 16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a f32x16 {
+    type Item = &'a f32;
+    type IntoIter = slice::Iter<'a, f32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl Mul<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn mul(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo * rhs.lo, self.hi * rhs.hi) }
+}
+
+impl MulAssign<f32x16> for f32x16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f32x16) { *self = *self * rhs }
+}
+
+impl Add<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn add(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo + rhs.lo, self.hi + rhs.hi) }
+}
+
+impl AddAssign for f32x16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+}
+
+impl Sub<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn sub(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo - rhs.lo, self.hi - rhs.hi) }
+}
+
+impl SubAssign for f32x16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+impl Div<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn div(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo / rhs.lo, self.hi / rhs.hi) }
+}
+
+impl DivAssign for f32x16 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs }
+}
+
+impl Neg for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn neg(self) -> Self::Output { Self::concat(-self.lo, -self.hi) }
+}
+
+impl BitAnd<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn bitand(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo & rhs.lo, self.hi & rhs.hi) }
+}
+
+impl BitAndAssign for f32x16 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn bitor(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo | rhs.lo, self.hi | rhs.hi) }
+}
+
+impl BitOrAssign for f32x16 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl BitXor<f32x16> for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn bitxor(self, rhs: f32x16) -> Self::Output { Self::concat(self.lo ^ rhs.lo, self.hi ^ rhs.hi) }
+}
+
+impl BitXorAssign for f32x16 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { *self = *self ^ rhs }
+}
+
+impl Not for f32x16 {
+    type Output = f32x16;
+
+    #[inline]
+    fn not(self) -> Self::Output { Self::concat(!self.lo, !self.hi) }
 }
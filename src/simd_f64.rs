@@ -1,13 +1,25 @@
-#[cfg(target_arch = "x86")]
-use std::arch::x86::*;
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
-
-use crate::{f32x8, i32x8, i64x4};
-use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::{
+    aligned::is_aligned, arch::*, bounds::OutOfBounds, detect::assert_supported, f32x16, f32x4, f32x8, i32x16, i32x4,
+    i32x8, i64x2, i64x4, i64x8, lanes::fmt_lanes, m64x2, m64x4, m64x8, partial::mask_epi64x2, partial::mask_epi64x4,
+    reduce::first_lane, scatter::scatter, swizzle::shift_left128, swizzle::shift_left256, swizzle::shift_right128,
+    swizzle::shift_right256, swizzle::shuffle128, swizzle::shuffle256, swizzle::swizzle128, swizzle::swizzle256,
+    swizzle::Broadcast, swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd, swizzle::InterleaveHi,
+    swizzle::InterleaveLo, swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight, swizzle::Swizzle,
+};
+#[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+use crate::{avx512, detect::has_avx512f};
+use std::{
+    array, fmt,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Index, Mul,
+        MulAssign, Neg, Not, Sub, SubAssign,
+    },
+    slice,
+};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct f64x4 {
     v: __m256d,
 }
@@ -15,13 +27,17 @@ pub struct f64x4 {
 impl f64x4 {
     #[inline]
     pub fn new(v1: f64, v2: f64, v3: f64, v4: f64) -> Self {
+        assert_supported();
         Self {
             v: unsafe { _mm256_set_pd(v4, v3, v2, v1) },
         }
     }
 
     #[inline]
-    pub fn splat(v: f64) -> Self { unsafe { _mm256_set1_pd(v) }.into() }
+    pub fn splat(v: f64) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_pd(v) }.into()
+    }
 
     #[inline]
     pub fn permute<const IMM: i32>(self) -> Self { unsafe { _mm256_permute_pd::<IMM>(self.v) }.into() }
@@ -45,7 +61,234 @@ impl f64x4 {
     pub unsafe fn from_slice_unchecked(a: &[f64]) -> Self { Self::from_ptr(a.as_ptr()) }
 
     #[inline]
-    pub unsafe fn from_ptr(a: *const f64) -> Self { _mm256_loadu_pd(a).into() }
+    pub unsafe fn from_ptr(a: *const f64) -> Self {
+        assert_supported();
+        _mm256_loadu_pd(a).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[f64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[f64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const f64) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_pd(a).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 4 and zeroes the rest, or 4 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[f64]) -> Self {
+        if a.len() >= 4 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm256_maskload_pd(a.as_ptr(), mask_epi64x4(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[f64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const f64) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_castsi256_pd(_mm256_stream_load_si256(a as *const __m256i)).into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_ptr<const SCALE: i32>(a: *const f64, idx: i64x4) -> Self {
+        _mm256_i64gather_pd::<SCALE>(a, idx.into()).into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_unchecked<const SCALE: i32>(a: &[f64], idx: i64x4) -> Self {
+        Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[f64], idx: i64x4) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<8>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[f64], idx: i64x4) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<8>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[f64], idx: i64x4, mask: m64x4, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<8>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const f64, idx: i64x4, mask: m64x4, default: Self) -> Self {
+        _mm256_mask_i64gather_pd::<SCALE>(default.v, a, idx.into(), _mm256_castsi256_pd(mask.into())).into()
+    }
+
+    /// [`gather_ptr`](Self::gather_ptr) with 32-bit indices.
+    #[inline]
+    pub unsafe fn gather_i32_ptr<const SCALE: i32>(a: *const f64, idx: i32x4) -> Self {
+        _mm256_i32gather_pd::<SCALE>(a, idx.into()).into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_i32_unchecked<const SCALE: i32>(a: &[f64], idx: i32x4) -> Self {
+        Self::gather_i32_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather_i32(a: &[f64], idx: i32x4) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_i32_unchecked::<8>(a, idx) }
+    }
+
+    /// [`gather_i32`](Self::gather_i32) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather_i32(a: &[f64], idx: i32x4) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_i32_unchecked::<8>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_i32_masked(a: &[f64], idx: i32x4, mask: m64x4, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_i32_masked_ptr::<8>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_i32_masked_ptr<const SCALE: i32>(
+        a: *const f64,
+        idx: i32x4,
+        mask: m64x4,
+        default: Self,
+    ) -> Self {
+        _mm256_mask_i32gather_pd::<SCALE>(default.v, a, idx.into(), _mm256_castsi256_pd(mask.into())).into()
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [f64], idx: i64x4) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<8>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [f64], idx: i64x4) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<8>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [f64], idx: i64x4, mask: m64x4) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<8>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut f64, idx: i64x4) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m64x4::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut f64, idx: i64x4, mask: m64x4) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i64scatter_pd_256::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        let mut v = [0.0; 4];
+        let mut i = [0; 4];
+        self.store(&mut v);
+        idx.store(&mut i);
+        scatter(a, v, i, mask.to_bitmask() as u32, SCALE)
+    }
 
     #[inline]
     pub fn to_raw_i32(self) -> i32x8 { unsafe { _mm256_castpd_si256(self.v) }.into() }
@@ -56,6 +299,48 @@ impl f64x4 {
     #[inline]
     pub fn to_raw_f32(self) -> f32x8 { unsafe { _mm256_castpd_ps(self.v) }.into() }
 
+    #[inline]
+    pub fn to_f32(self) -> f32x4 { unsafe { _mm256_cvtpd_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn trunc(self) -> i32x4 { unsafe { _mm256_cvttpd_epi32(self.v) }.into() }
+
+    #[inline]
+    pub fn floor(self) -> Self { unsafe { _mm256_floor_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn ceil(self) -> Self { unsafe { _mm256_ceil_pd(self.v) }.into() }
+
+    /// Rounds half-way cases away from zero, like `f64::round`.
+    #[inline]
+    pub fn round(self) -> Self {
+        unsafe {
+            let sign = _mm256_set1_pd(-0.0);
+            let trunc = _mm256_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v);
+            let frac = _mm256_andnot_pd(sign, _mm256_sub_pd(self.v, trunc));
+            let away = _mm256_and_pd(_mm256_cmp_pd::<_CMP_GE_OQ>(frac, _mm256_set1_pd(0.5)), _mm256_set1_pd(1.0));
+            // Adding a signed zero keeps the sign of results that truncate to zero
+            _mm256_add_pd(trunc, _mm256_or_pd(away, _mm256_and_pd(self.v, sign)))
+        }
+        .into()
+    }
+
+    /// Rounds half-way cases to the even integer, like `f64::round_ties_even`.
+    #[inline]
+    pub fn round_ties_even(self) -> Self {
+        unsafe { _mm256_round_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self.v) }.into()
+    }
+
+    #[inline]
+    pub fn fract(self) -> Self {
+        unsafe { _mm256_sub_pd(self.v, _mm256_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v)) }.into()
+    }
+
+    /// Converts with the MXCSR rounding mode, to nearest with ties to even unless changed. Out of range lanes become
+    /// `i32::MIN`.
+    #[inline]
+    pub fn round_int(self) -> i32x4 { unsafe { _mm256_cvtpd_epi32(self.v) }.into() }
+
     #[inline]
     pub fn blend<const IMM: i32>(self, other: Self) -> Self {
         unsafe { _mm256_blend_pd::<IMM>(self.v, other.v) }.into()
@@ -76,6 +361,62 @@ impl f64x4 {
     #[inline]
     pub unsafe fn store_ptr(self, a: *mut f64) { _mm256_storeu_pd(a, self.v) }
 
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [f64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [f64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut f64) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_pd(a, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 4, or 4 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [f64]) {
+        if a.len() >= 4 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm256_maskstore_pd(a.as_mut_ptr(), mask_epi64x4(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [f64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut f64) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_pd(a, self.v)
+    }
+
     #[inline]
     pub fn hadd(self, other: Self) -> Self { unsafe { _mm256_hadd_pd(self.v, other.v) }.into() }
 
@@ -86,51 +427,1623 @@ impl f64x4 {
     pub fn max(self, other: Self) -> Self { unsafe { _mm256_max_pd(self.v, other.v) }.into() }
 
     #[inline]
-    pub fn extract<const INDEX: i32>(self) -> f64 { f64::from_bits(self.to_raw_i64().extract::<INDEX>() as u64) }
-}
+    pub fn sum(self) -> f64 { self.reduce(|a, b| unsafe { _mm_add_pd(a, b) }) }
 
-impl From<__m256d> for f64x4 {
     #[inline]
-    fn from(v: __m256d) -> Self { Self { v } }
-}
+    fn reduce(self, op: impl Fn(__m128d, __m128d) -> __m128d) -> f64 {
+        unsafe {
+            let v = op(_mm256_castpd256_pd128(self.v), _mm256_extractf128_pd::<1>(self.v));
+            _mm_cvtsd_f64(op(v, _mm_unpackhi_pd(v, v)))
+        }
+    }
 
-impl From<f64x4> for __m256d {
     #[inline]
-    fn from(v: f64x4) -> Self { v.v }
-}
+    pub fn product(self) -> f64 { self.reduce(|a, b| unsafe { _mm_mul_pd(a, b) }) }
 
-impl Mul<f64x4> for f64x4 {
-    type Output = f64x4;
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_min(self) -> f64 {
+        self.reduce(|a, b| unsafe { _mm_blendv_pd(_mm_min_pd(a, b), a, _mm_cmp_pd::<_CMP_UNORD_Q>(b, b)) })
+    }
 
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
     #[inline]
-    fn mul(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_mul_pd(self.v, rhs.v) }.into() }
-}
+    pub fn reduce_max(self) -> f64 {
+        self.reduce(|a, b| unsafe { _mm_blendv_pd(_mm_max_pd(a, b), a, _mm_cmp_pd::<_CMP_UNORD_Q>(b, b)) })
+    }
 
-impl MulAssign<f64x4> for f64x4 {
+    /// Index of the first lane holding the minimum, ignoring NaN lanes. 0 if all lanes are NaN.
     #[inline]
-    fn mul_assign(&mut self, rhs: f64x4) { self.v = (*self * rhs).v }
-}
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
 
-impl Add<f64x4> for f64x4 {
-    type Output = f64x4;
+    /// Index of the first lane holding the maximum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
 
     #[inline]
-    fn add(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_add_pd(self.v, rhs.v) }.into() }
-}
+    pub fn mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm256_fmadd_pd(self.v, mul.v, add.v) }.into() }
 
-impl AddAssign for f64x4 {
     #[inline]
-    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
-}
+    pub fn mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm256_fmsub_pd(self.v, mul.v, sub.v) }.into() }
 
-impl Sub<f64x4> for f64x4 {
-    type Output = f64x4;
+    #[inline]
+    pub fn neg_mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm256_fnmadd_pd(self.v, mul.v, add.v) }.into() }
 
     #[inline]
-    fn sub(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_sub_pd(self.v, rhs.v) }.into() }
-}
+    pub fn neg_mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm256_fnmsub_pd(self.v, mul.v, sub.v) }.into() }
 
-impl SubAssign for f64x4 {
+    /// Subtracts `addsub` in even lanes and adds it in odd lanes.
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+    pub fn mul_addsub(self, mul: Self, addsub: Self) -> Self {
+        unsafe { _mm256_fmaddsub_pd(self.v, mul.v, addsub.v) }.into()
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self { unsafe { _mm256_andnot_pd(_mm256_set1_pd(-0.0), self.v) }.into() }
+
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Self {
+        unsafe {
+            let mask = _mm256_set1_pd(-0.0);
+            _mm256_or_pd(_mm256_andnot_pd(mask, self.v), _mm256_and_pd(mask, sign.v))
+        }
+        .into()
+    }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_pd(other.v, self.v) }.into() }
+
+    /// Clears everything but the sign bit of each lane.
+    #[inline]
+    pub fn sign_bit(self) -> Self { unsafe { _mm256_and_pd(self.v, _mm256_set1_pd(-0.0)) }.into() }
+
+    /// The sign bit of each lane, lane 0 in the lowest bit.
+    #[inline]
+    pub fn sign_bitmask(self) -> u8 { unsafe { _mm256_movemask_pd(self.v) as u8 } }
+
+    /// `1.0` or `-1.0` with the sign of `self`, including for zeros, and NaN for NaN like `f64::signum`.
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let one = _mm256_or_pd(_mm256_and_pd(self.v, _mm256_set1_pd(-0.0)), _mm256_set1_pd(1.0));
+            _mm256_blendv_pd(one, self.v, _mm256_cmp_pd::<_CMP_UNORD_Q>(self.v, self.v))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn is_nan(self) -> m64x4 { self.compare::<_CMP_UNORD_Q>(self) }
+
+    #[inline]
+    pub fn is_finite(self) -> m64x4 { self.abs().lt(unsafe { _mm256_set1_pd(f64::INFINITY) }.into()) }
+
+    #[inline]
+    pub fn sqrt(self) -> Self { unsafe { _mm256_sqrt_pd(self.v) }.into() }
+
+    /// Approximate `1 / self` with a relative error of at most 1.5 * 2^-12, computed in single precision so only
+    /// valid while `self` is within the normal `f32` range.
+    #[inline]
+    pub fn rcp(self) -> Self { unsafe { _mm256_cvtps_pd(_mm_rcp_ps(_mm256_cvtpd_ps(self.v))) }.into() }
+
+    /// `rcp` of the mantissa refined with three Newton-Raphson steps and rescaled by the exponent, at most 1 ULP from
+    /// `1 / self` over the whole `f64` range.
+    #[inline]
+    pub fn rcp_nr(self) -> Self {
+        unsafe {
+            let one = _mm256_set1_pd(1.0);
+            let sign = _mm256_set1_pd(-0.0);
+            let abs = _mm256_andnot_pd(sign, self.v);
+            // Subnormals have no exponent to split off, scale them by 2^54 first
+            let tiny = _mm256_cmp_pd::<_CMP_LT_OQ>(abs, _mm256_set1_pd(f64::MIN_POSITIVE));
+            let a = _mm256_castpd_si256(_mm256_blendv_pd(abs, _mm256_mul_pd(abs, _mm256_set1_pd(2f64.powi(54))), tiny));
+            let e = _mm256_srli_epi64::<52>(a);
+            let m = _mm256_or_pd(_mm256_castsi256_pd(_mm256_and_si256(a, _mm256_set1_epi64x(0xf_ffff_ffff_ffff))), one);
+            let mut x = _mm256_cvtps_pd(_mm_rcp_ps(_mm256_cvtpd_ps(m)));
+
+            for _ in 0..3 {
+                x = _mm256_fmadd_pd(x, _mm256_fnmadd_pd(m, x, one), x);
+            }
+
+            // 2^(1023 - e) split into two biased exponents that stay within range, the second rounds subnormal results
+            let t = _mm256_and_si256(_mm256_castpd_si256(tiny), _mm256_set1_epi64x(54));
+            let total = _mm256_sub_epi64(_mm256_add_epi64(_mm256_set1_epi64x(3069), t), e);
+            let p = _mm256_srli_epi64::<1>(total);
+            let q = _mm256_sub_epi64(total, p);
+            x = _mm256_mul_pd(x, _mm256_castsi256_pd(_mm256_slli_epi64::<52>(p)));
+            x = _mm256_mul_pd(x, _mm256_castsi256_pd(_mm256_slli_epi64::<52>(q)));
+            x = _mm256_or_pd(x, _mm256_and_pd(self.v, sign));
+            // 0, inf and NaN get the estimate, which is exact for them
+            let finite = _mm256_cmp_pd::<_CMP_LT_OQ>(abs, _mm256_set1_pd(f64::INFINITY));
+            let regular = _mm256_and_pd(finite, _mm256_cmp_pd::<_CMP_NEQ_OQ>(abs, _mm256_setzero_pd()));
+            _mm256_blendv_pd(self.rcp().v, x, regular)
+        }
+        .into()
+    }
+
+    /// Approximate `1 / sqrt(self)` with a relative error of at most 1.5 * 2^-12, computed in single precision so
+    /// only valid while `self` is within the normal `f32` range.
+    #[inline]
+    pub fn rsqrt(self) -> Self { unsafe { _mm256_cvtps_pd(_mm_rsqrt_ps(_mm256_cvtpd_ps(self.v))) }.into() }
+
+    /// `rsqrt` of the mantissa refined with three Newton-Raphson steps and rescaled by the exponent, at most 1 ULP
+    /// from `1 / sqrt(self)` over the whole `f64` range.
+    #[inline]
+    pub fn rsqrt_nr(self) -> Self {
+        unsafe {
+            let half = _mm256_set1_pd(0.5);
+            // Subnormals have no exponent to split off, scale them by 2^54 first
+            let tiny = _mm256_cmp_pd::<_CMP_LT_OQ>(self.v, _mm256_set1_pd(f64::MIN_POSITIVE));
+            let a = _mm256_blendv_pd(self.v, _mm256_mul_pd(self.v, _mm256_set1_pd(2f64.powi(54))), tiny);
+            let a = _mm256_castpd_si256(a);
+            let e = _mm256_srli_epi64::<52>(a);
+            // Round the exponent down to an even power so the mantissa is in [1, 4)
+            let d = _mm256_or_si256(_mm256_sub_epi64(e, _mm256_set1_epi64x(1)), _mm256_set1_epi64x(1));
+            let me = _mm256_slli_epi64::<52>(_mm256_sub_epi64(_mm256_add_epi64(e, _mm256_set1_epi64x(1023)), d));
+            let m =
+                _mm256_castsi256_pd(_mm256_or_si256(_mm256_and_si256(a, _mm256_set1_epi64x(0xf_ffff_ffff_ffff)), me));
+            let h = _mm256_mul_pd(m, half);
+            let mut y = _mm256_cvtps_pd(_mm_rsqrt_ps(_mm256_cvtpd_ps(m)));
+
+            for _ in 0..3 {
+                y = _mm256_fmadd_pd(y, _mm256_fnmadd_pd(_mm256_mul_pd(h, y), y, half), y);
+            }
+
+            // 2^((1023 - d) / 2), plus 2^27 to undo the subnormal scaling
+            let t = _mm256_and_si256(_mm256_castpd_si256(tiny), _mm256_set1_epi64x(27));
+            let exp = _mm256_add_epi64(_mm256_srli_epi64::<1>(_mm256_sub_epi64(_mm256_set1_epi64x(3069), d)), t);
+            y = _mm256_mul_pd(y, _mm256_castsi256_pd(_mm256_slli_epi64::<52>(exp)));
+            // 0, inf, negatives and NaN get the estimate, which is exact for them
+            let positive = _mm256_cmp_pd::<_CMP_GT_OQ>(self.v, _mm256_setzero_pd());
+            let regular = _mm256_and_pd(positive, _mm256_cmp_pd::<_CMP_LT_OQ>(self.v, _mm256_set1_pd(f64::INFINITY)));
+            _mm256_blendv_pd(self.rsqrt().v, y, regular)
+        }
+        .into()
+    }
+
+    #[inline]
+    fn compare<const IMM: i32>(self, other: Self) -> m64x4 {
+        unsafe { _mm256_castpd_si256(_mm256_cmp_pd::<IMM>(self.v, other.v)) }.into()
+    }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x4 { self.compare::<_CMP_EQ_OQ>(other) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x4 { self.compare::<_CMP_NEQ_UQ>(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x4 { self.compare::<_CMP_LT_OQ>(other) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x4 { self.compare::<_CMP_LE_OQ>(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x4 { self.compare::<_CMP_GT_OQ>(other) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x4 { self.compare::<_CMP_GE_OQ>(other) }
+
+    #[inline]
+    pub fn select(mask: m64x4, a: Self, b: Self) -> Self {
+        unsafe { _mm256_blendv_pd(b.v, a.v, _mm256_castsi256_pd(mask.into())) }.into()
+    }
+
+    #[inline]
+    pub fn lo(self) -> f64x2 { unsafe { _mm256_castpd256_pd128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> f64x2 { unsafe { _mm256_extractf128_pd::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: f64x2, hi: f64x2) -> Self { unsafe { _mm256_set_m128d(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> f64 { f64::from_bits(self.to_raw_i64().extract::<INDEX>() as u64) }
+
+    #[inline]
+    pub fn to_array(self) -> [f64; 4] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[f64; 4] { unsafe { &*(self as *const Self as *const [f64; 4]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> f64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: f64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> f64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self {
+        unsafe { _mm256_castsi256_pd(swizzle256::<S, 4>(_mm256_castpd_si256(self.v))) }.into()
+    }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        unsafe { _mm256_castsi256_pd(shuffle256::<S, 4>(_mm256_castpd_si256(self.v), _mm256_castpd_si256(other.v))) }
+            .into()
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<4>>() }
+
+    /// Lane `(i + N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<4, N>>() }
+
+    /// Lane `(i - N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<4, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm256_castsi256_pd(shift_left256::<4, N>(_mm256_castpd_si256(self.v), _mm256_castpd_si256(fill.v))) }
+            .into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm256_castsi256_pd(shift_right256::<4, N>(_mm256_castpd_si256(self.v), _mm256_castpd_si256(fill.v))) }
+            .into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<4, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<4>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<4>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<4>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<4>>(other) }
+}
+
+impl Default for f64x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(0.0) }
+}
+
+impl PartialEq for f64x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { f64x4::eq(*self, *other).all() }
+}
+
+impl From<[f64; 4]> for f64x4 {
+    #[inline]
+    fn from(a: [f64; 4]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<f64x4> for [f64; 4] {
+    #[inline]
+    fn from(v: f64x4) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for f64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("f64x4").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for f64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for f64x4 {
+    type Output = f64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &f64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for f64x4 {
+    type Item = f64;
+    type IntoIter = array::IntoIter<f64, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a f64x4 {
+    type Item = &'a f64;
+    type IntoIter = slice::Iter<'a, f64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256d> for f64x4 {
+    #[inline]
+    fn from(v: __m256d) -> Self { Self { v } }
+}
+
+impl From<f64x4> for __m256d {
+    #[inline]
+    fn from(v: f64x4) -> Self { v.v }
+}
+
+impl Mul<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn mul(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_mul_pd(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<f64x4> for f64x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64x4) { self.v = (*self * rhs).v }
+}
+
+impl Add<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn add(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_add_pd(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for f64x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn sub(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_sub_pd(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for f64x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl Div<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn div(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_div_pd(self.v, rhs.v) }.into() }
+}
+
+impl DivAssign for f64x4 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) { self.v = (*self / rhs).v }
+}
+
+impl Neg for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn neg(self) -> Self::Output { unsafe { _mm256_xor_pd(self.v, _mm256_set1_pd(-0.0)) }.into() }
+}
+
+impl BitAnd<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn bitand(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_and_pd(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for f64x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn bitor(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_or_pd(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for f64x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<f64x4> for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn bitxor(self, rhs: f64x4) -> Self::Output { unsafe { _mm256_xor_pd(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for f64x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for f64x4 {
+    type Output = f64x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_pd(self.v, _mm256_castsi256_pd(_mm256_set1_epi32(-1))) }.into() }
+}
+
+/// The 128-bit counterpart of [`f64x4`]. Gathers, scatters, `permute4x64` and `lo`/`hi`/`concat` are 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct f64x2 {
+    v: __m128d,
+}
+
+impl f64x2 {
+    #[inline]
+    pub fn new(v1: f64, v2: f64) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm_set_pd(v2, v1) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: f64) -> f64x2 {
+        assert_supported();
+        unsafe { _mm_set1_pd(v) }.into()
+    }
+
+    #[inline]
+    pub fn permute<const IMM: i32>(self) -> Self { unsafe { _mm_permute_pd::<IMM>(self.v) }.into() }
+
+    #[inline]
+    pub fn permute_var(self, idx: i32x4) -> Self { unsafe { _mm_permutevar_pd(self.v, idx.into()) }.into() }
+
+    #[inline]
+    pub fn from_slice(a: &[f64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[f64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const f64) -> Self {
+        assert_supported();
+        _mm_loadu_pd(a).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[f64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[f64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const f64) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_pd(a).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 2 and zeroes the rest, or 2 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[f64]) -> Self {
+        if a.len() >= 2 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm_maskload_pd(a.as_ptr(), mask_epi64x2(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[f64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const f64) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_castsi128_pd(_mm_stream_load_si128(a as *const __m128i)).into()
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x4 { unsafe { _mm_castpd_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x2 { unsafe { _mm_castpd_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x4 { unsafe { _mm_castpd_ps(self.v) }.into() }
+
+    /// Converts into the lower two lanes, the upper two are zero.
+    #[inline]
+    pub fn to_f32(self) -> f32x4 { unsafe { _mm_cvtpd_ps(self.v) }.into() }
+
+    /// Truncates into the lower two lanes, the upper two are zero.
+    #[inline]
+    pub fn trunc(self) -> i32x4 { unsafe { _mm_cvttpd_epi32(self.v) }.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [f64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [f64]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut f64) { _mm_storeu_pd(a, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [f64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [f64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut f64) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_pd(a, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 2, or 2 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [f64]) {
+        if a.len() >= 2 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm_maskstore_pd(a.as_mut_ptr(), mask_epi64x2(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [f64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut f64) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_pd(a, self.v)
+    }
+
+    #[inline]
+    pub fn floor(self) -> Self { unsafe { _mm_floor_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn ceil(self) -> Self { unsafe { _mm_ceil_pd(self.v) }.into() }
+
+    /// Rounds half-way cases away from zero, like `f64::round`.
+    #[inline]
+    pub fn round(self) -> Self {
+        unsafe {
+            let sign = _mm_set1_pd(-0.0);
+            let trunc = _mm_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v);
+            let frac = _mm_andnot_pd(sign, _mm_sub_pd(self.v, trunc));
+            let away = _mm_and_pd(_mm_cmp_pd::<_CMP_GE_OQ>(frac, _mm_set1_pd(0.5)), _mm_set1_pd(1.0));
+            // Adding a signed zero keeps the sign of results that truncate to zero
+            _mm_add_pd(trunc, _mm_or_pd(away, _mm_and_pd(self.v, sign)))
+        }
+        .into()
+    }
+
+    /// Rounds half-way cases to the even integer, like `f64::round_ties_even`.
+    #[inline]
+    pub fn round_ties_even(self) -> Self {
+        unsafe { _mm_round_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(self.v) }.into()
+    }
+
+    #[inline]
+    pub fn fract(self) -> Self {
+        unsafe { _mm_sub_pd(self.v, _mm_round_pd::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(self.v)) }.into()
+    }
+
+    /// Converts with the MXCSR rounding mode, to nearest with ties to even unless changed. Out of range lanes become
+    /// `i32::MIN`, the upper two lanes are zero.
+    #[inline]
+    pub fn round_int(self) -> i32x4 { unsafe { _mm_cvtpd_epi32(self.v) }.into() }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self { unsafe { _mm_blend_pd::<IMM>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn hadd(self, other: Self) -> Self { unsafe { _mm_hadd_pd(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm_fmadd_pd(self.v, mul.v, add.v) }.into() }
+
+    #[inline]
+    pub fn mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm_fmsub_pd(self.v, mul.v, sub.v) }.into() }
+
+    #[inline]
+    pub fn neg_mul_add(self, mul: Self, add: Self) -> Self { unsafe { _mm_fnmadd_pd(self.v, mul.v, add.v) }.into() }
+
+    #[inline]
+    pub fn neg_mul_sub(self, mul: Self, sub: Self) -> Self { unsafe { _mm_fnmsub_pd(self.v, mul.v, sub.v) }.into() }
+
+    /// Subtracts `addsub` in even lanes and adds it in odd lanes.
+    #[inline]
+    pub fn mul_addsub(self, mul: Self, addsub: Self) -> Self {
+        unsafe { _mm_fmaddsub_pd(self.v, mul.v, addsub.v) }.into()
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self { unsafe { _mm_andnot_pd(_mm_set1_pd(-0.0), self.v) }.into() }
+
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Self {
+        unsafe {
+            let mask = _mm_set1_pd(-0.0);
+            _mm_or_pd(_mm_andnot_pd(mask, self.v), _mm_and_pd(mask, sign.v))
+        }
+        .into()
+    }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_pd(other.v, self.v) }.into() }
+
+    /// Clears everything but the sign bit of each lane.
+    #[inline]
+    pub fn sign_bit(self) -> Self { unsafe { _mm_and_pd(self.v, _mm_set1_pd(-0.0)) }.into() }
+
+    /// The sign bit of each lane, lane 0 in the lowest bit.
+    #[inline]
+    pub fn sign_bitmask(self) -> u8 { unsafe { _mm_movemask_pd(self.v) as u8 } }
+
+    /// `1.0` or `-1.0` with the sign of `self`, including for zeros, and NaN for NaN like `f64::signum`.
+    #[inline]
+    pub fn signum(self) -> Self {
+        unsafe {
+            let one = _mm_or_pd(_mm_and_pd(self.v, _mm_set1_pd(-0.0)), _mm_set1_pd(1.0));
+            _mm_blendv_pd(one, self.v, _mm_cmp_pd::<_CMP_UNORD_Q>(self.v, self.v))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn is_nan(self) -> m64x2 { self.compare::<_CMP_UNORD_Q>(self) }
+
+    #[inline]
+    pub fn is_finite(self) -> m64x2 { self.abs().lt(unsafe { _mm_set1_pd(f64::INFINITY) }.into()) }
+
+    #[inline]
+    pub fn sqrt(self) -> Self { unsafe { _mm_sqrt_pd(self.v) }.into() }
+
+    /// Approximate `1 / self` with a relative error of at most 1.5 * 2^-12, computed in single precision so only
+    /// valid while `self` is within the normal `f32` range.
+    #[inline]
+    pub fn rcp(self) -> Self { unsafe { _mm_cvtps_pd(_mm_rcp_ps(_mm_cvtpd_ps(self.v))) }.into() }
+
+    /// `rcp` of the mantissa refined with three Newton-Raphson steps and rescaled by the exponent, at most 1 ULP from
+    /// `1 / self` over the whole `f64` range.
+    #[inline]
+    pub fn rcp_nr(self) -> Self {
+        unsafe {
+            let one = _mm_set1_pd(1.0);
+            let sign = _mm_set1_pd(-0.0);
+            let abs = _mm_andnot_pd(sign, self.v);
+            // Subnormals have no exponent to split off, scale them by 2^54 first
+            let tiny = _mm_cmp_pd::<_CMP_LT_OQ>(abs, _mm_set1_pd(f64::MIN_POSITIVE));
+            let a = _mm_castpd_si128(_mm_blendv_pd(abs, _mm_mul_pd(abs, _mm_set1_pd(2f64.powi(54))), tiny));
+            let e = _mm_srli_epi64::<52>(a);
+            let m = _mm_or_pd(_mm_castsi128_pd(_mm_and_si128(a, _mm_set1_epi64x(0xf_ffff_ffff_ffff))), one);
+            let mut x = _mm_cvtps_pd(_mm_rcp_ps(_mm_cvtpd_ps(m)));
+
+            for _ in 0..3 {
+                x = _mm_fmadd_pd(x, _mm_fnmadd_pd(m, x, one), x);
+            }
+
+            // 2^(1023 - e) split into two biased exponents that stay within range, the second rounds subnormal results
+            let t = _mm_and_si128(_mm_castpd_si128(tiny), _mm_set1_epi64x(54));
+            let total = _mm_sub_epi64(_mm_add_epi64(_mm_set1_epi64x(3069), t), e);
+            let p = _mm_srli_epi64::<1>(total);
+            let q = _mm_sub_epi64(total, p);
+            x = _mm_mul_pd(x, _mm_castsi128_pd(_mm_slli_epi64::<52>(p)));
+            x = _mm_mul_pd(x, _mm_castsi128_pd(_mm_slli_epi64::<52>(q)));
+            x = _mm_or_pd(x, _mm_and_pd(self.v, sign));
+            // 0, inf and NaN get the estimate, which is exact for them
+            let finite = _mm_cmp_pd::<_CMP_LT_OQ>(abs, _mm_set1_pd(f64::INFINITY));
+            let regular = _mm_and_pd(finite, _mm_cmp_pd::<_CMP_NEQ_OQ>(abs, _mm_setzero_pd()));
+            _mm_blendv_pd(self.rcp().v, x, regular)
+        }
+        .into()
+    }
+
+    /// Approximate `1 / sqrt(self)` with a relative error of at most 1.5 * 2^-12, computed in single precision so
+    /// only valid while `self` is within the normal `f32` range.
+    #[inline]
+    pub fn rsqrt(self) -> Self { unsafe { _mm_cvtps_pd(_mm_rsqrt_ps(_mm_cvtpd_ps(self.v))) }.into() }
+
+    /// `rsqrt` of the mantissa refined with three Newton-Raphson steps and rescaled by the exponent, at most 1 ULP
+    /// from `1 / sqrt(self)` over the whole `f64` range.
+    #[inline]
+    pub fn rsqrt_nr(self) -> Self {
+        unsafe {
+            let half = _mm_set1_pd(0.5);
+            // Subnormals have no exponent to split off, scale them by 2^54 first
+            let tiny = _mm_cmp_pd::<_CMP_LT_OQ>(self.v, _mm_set1_pd(f64::MIN_POSITIVE));
+            let a = _mm_blendv_pd(self.v, _mm_mul_pd(self.v, _mm_set1_pd(2f64.powi(54))), tiny);
+            let a = _mm_castpd_si128(a);
+            let e = _mm_srli_epi64::<52>(a);
+            // Round the exponent down to an even power so the mantissa is in [1, 4)
+            let d = _mm_or_si128(_mm_sub_epi64(e, _mm_set1_epi64x(1)), _mm_set1_epi64x(1));
+            let me = _mm_slli_epi64::<52>(_mm_sub_epi64(_mm_add_epi64(e, _mm_set1_epi64x(1023)), d));
+            let m = _mm_castsi128_pd(_mm_or_si128(_mm_and_si128(a, _mm_set1_epi64x(0xf_ffff_ffff_ffff)), me));
+            let h = _mm_mul_pd(m, half);
+            let mut y = _mm_cvtps_pd(_mm_rsqrt_ps(_mm_cvtpd_ps(m)));
+
+            for _ in 0..3 {
+                y = _mm_fmadd_pd(y, _mm_fnmadd_pd(_mm_mul_pd(h, y), y, half), y);
+            }
+
+            // 2^((1023 - d) / 2), plus 2^27 to undo the subnormal scaling
+            let t = _mm_and_si128(_mm_castpd_si128(tiny), _mm_set1_epi64x(27));
+            let exp = _mm_add_epi64(_mm_srli_epi64::<1>(_mm_sub_epi64(_mm_set1_epi64x(3069), d)), t);
+            y = _mm_mul_pd(y, _mm_castsi128_pd(_mm_slli_epi64::<52>(exp)));
+            // 0, inf, negatives and NaN get the estimate, which is exact for them
+            let positive = _mm_cmp_pd::<_CMP_GT_OQ>(self.v, _mm_setzero_pd());
+            let regular = _mm_and_pd(positive, _mm_cmp_pd::<_CMP_LT_OQ>(self.v, _mm_set1_pd(f64::INFINITY)));
+            _mm_blendv_pd(self.rsqrt().v, y, regular)
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn sum(self) -> f64 { self.reduce(|a, b| unsafe { _mm_add_pd(a, b) }) }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128d, __m128d) -> __m128d) -> f64 {
+        unsafe { _mm_cvtsd_f64(op(self.v, _mm_unpackhi_pd(self.v, self.v))) }
+    }
+
+    #[inline]
+    pub fn product(self) -> f64 { self.reduce(|a, b| unsafe { _mm_mul_pd(a, b) }) }
+
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_min(self) -> f64 {
+        self.reduce(|a, b| unsafe { _mm_blendv_pd(_mm_min_pd(a, b), a, _mm_cmp_pd::<_CMP_UNORD_Q>(b, b)) })
+    }
+
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_max(self) -> f64 {
+        self.reduce(|a, b| unsafe { _mm_blendv_pd(_mm_max_pd(a, b), a, _mm_cmp_pd::<_CMP_UNORD_Q>(b, b)) })
+    }
+
+    /// Index of the first lane holding the minimum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm_min_pd(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm_max_pd(self.v, other.v) }.into() }
+
+    #[inline]
+    fn compare<const IMM: i32>(self, other: Self) -> m64x2 {
+        unsafe { _mm_castpd_si128(_mm_cmp_pd::<IMM>(self.v, other.v)) }.into()
+    }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x2 { self.compare::<_CMP_EQ_OQ>(other) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x2 { self.compare::<_CMP_NEQ_UQ>(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x2 { self.compare::<_CMP_LT_OQ>(other) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x2 { self.compare::<_CMP_LE_OQ>(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x2 { self.compare::<_CMP_GT_OQ>(other) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x2 { self.compare::<_CMP_GE_OQ>(other) }
+
+    #[inline]
+    pub fn select(mask: m64x2, a: Self, b: Self) -> Self {
+        unsafe { _mm_blendv_pd(b.v, a.v, _mm_castsi128_pd(mask.into())) }.into()
+    }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> f64 { f64::from_bits(self.to_raw_i64().extract::<INDEX>() as u64) }
+
+    #[inline]
+    pub fn to_array(self) -> [f64; 2] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[f64; 2] { unsafe { &*(self as *const Self as *const [f64; 2]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> f64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: f64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> f64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self {
+        unsafe { _mm_castsi128_pd(swizzle128::<S, 2>(_mm_castpd_si128(self.v))) }.into()
+    }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        unsafe { _mm_castsi128_pd(shuffle128::<S, 2>(_mm_castpd_si128(self.v), _mm_castpd_si128(other.v))) }.into()
+    }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<2>>() }
+
+    /// Lane `(i + N) % 2` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<2, N>>() }
+
+    /// Lane `(i - N) % 2` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<2, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 2.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm_castsi128_pd(shift_left128::<2, N>(_mm_castpd_si128(self.v), _mm_castpd_si128(fill.v))) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 2.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { _mm_castsi128_pd(shift_right128::<2, N>(_mm_castpd_si128(self.v), _mm_castpd_si128(fill.v))) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<2, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<2>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<2>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<2>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<2>>(other) }
+}
+
+impl Default for f64x2 {
+    #[inline]
+    fn default() -> Self { Self::splat(0.0) }
+}
+
+impl PartialEq for f64x2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { f64x2::eq(*self, *other).all() }
+}
+
+impl From<[f64; 2]> for f64x2 {
+    #[inline]
+    fn from(a: [f64; 2]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<f64x2> for [f64; 2] {
+    #[inline]
+    fn from(v: f64x2) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for f64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("f64x2").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for f64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for f64x2 {
+    type Output = f64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &f64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for f64x2 {
+    type Item = f64;
+    type IntoIter = array::IntoIter<f64, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a f64x2 {
+    type Item = &'a f64;
+    type IntoIter = slice::Iter<'a, f64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128d> for f64x2 {
+    #[inline]
+    fn from(v: __m128d) -> Self { Self { v } }
+}
+
+impl From<f64x2> for __m128d {
+    #[inline]
+    fn from(v: f64x2) -> Self { v.v }
+}
+
+impl Mul<f64x2> for f64x2 {
+    type Output = f64x2;
+
+    #[inline]
+    fn mul(self, rhs: f64x2) -> Self::Output { unsafe { _mm_mul_pd(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<f64x2> for f64x2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64x2) { self.v = (*self * rhs).v }
+}
+
+impl Add<f64x2> for f64x2 {
+    type Output = f64x2;
+
+    #[inline]
+    fn add(self, rhs: f64x2) -> Self::Output { unsafe { _mm_add_pd(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for f64x2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<f64x2> for f64x2 {
+    type Output = f64x2;
+
+    #[inline]
+    fn sub(self, rhs: f64x2) -> Self::Output { unsafe { _mm_sub_pd(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for f64x2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl Div<f64x2> for f64x2 {
+    type Output = f64x2;
+
+    #[inline]
+    fn div(self, rhs: f64x2) -> Self::Output { unsafe { _mm_div_pd(self.v, rhs.v) }.into() }
+}
+
+impl DivAssign for f64x2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: f64x2) { self.v = (*self / rhs).v }
+}
+
+impl Neg for f64x2 {
+    type Output = f64x2;
+
+    #[inline]
+    fn neg(self) -> Self::Output { unsafe { _mm_xor_pd(self.v, _mm_set1_pd(-0.0)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct f64x8 {
+    lo: f64x4,
+    hi: f64x4,
+}
+
+impl f64x8 {
+    #[inline]
+    pub fn new(v1: f64, v2: f64, v3: f64, v4: f64, v5: f64, v6: f64, v7: f64, v8: f64) -> Self {
+        Self::concat(f64x4::new(v1, v2, v3, v4), f64x4::new(v5, v6, v7, v8))
+    }
+
+    #[inline]
+    pub fn splat(v: f64) -> Self { Self::concat(f64x4::splat(v), f64x4::splat(v)) }
+
+    #[inline]
+    pub fn from_slice(a: &[f64]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[f64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const f64) -> Self { Self::concat(f64x4::from_ptr(a), f64x4::from_ptr(a.add(4))) }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[f64]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[f64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const f64) -> Self {
+        Self::concat(f64x4::from_ptr_aligned(a), f64x4::from_ptr_aligned(a.add(4)))
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[f64]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at(a.len().min(4));
+        Self::concat(f64x4::load_partial(lo), f64x4::load_partial(hi))
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[f64]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const f64) -> Self {
+        Self::concat(f64x4::from_ptr_stream(a), f64x4::from_ptr_stream(a.add(4)))
+    }
+
+    #[inline]
+    pub fn permute4x64<const IMM: i32>(self) -> Self {
+        Self::concat(self.lo.permute4x64::<IMM>(), self.hi.permute4x64::<IMM>())
+    }
+
+    #[inline]
+    pub fn permute_var(self, idx: i32x16) -> Self {
+        Self::concat(self.lo.permute_var(idx.lo()), self.hi.permute_var(idx.hi()))
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x16 { i32x16::concat(self.lo.to_raw_i32(), self.hi.to_raw_i32()) }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x8 { i64x8::concat(self.lo.to_raw_i64(), self.hi.to_raw_i64()) }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x16 { f32x16::concat(self.lo.to_raw_f32(), self.hi.to_raw_f32()) }
+
+    #[inline]
+    pub fn trunc(self) -> i32x8 { i32x8::concat(self.lo.trunc(), self.hi.trunc()) }
+
+    #[inline]
+    pub fn floor(self) -> Self { Self::concat(self.lo.floor(), self.hi.floor()) }
+
+    #[inline]
+    pub fn ceil(self) -> Self { Self::concat(self.lo.ceil(), self.hi.ceil()) }
+
+    #[inline]
+    pub fn round(self) -> Self { Self::concat(self.lo.round(), self.hi.round()) }
+
+    #[inline]
+    pub fn round_ties_even(self) -> Self { Self::concat(self.lo.round_ties_even(), self.hi.round_ties_even()) }
+
+    #[inline]
+    pub fn fract(self) -> Self { Self::concat(self.lo.fract(), self.hi.fract()) }
+
+    #[inline]
+    pub fn round_int(self) -> i32x8 { i32x8::concat(self.lo.round_int(), self.hi.round_int()) }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        Self::select(m64x8::from_bitmask(IMM as u8), other, self)
+    }
+
+    #[inline]
+    pub fn store(self, a: &mut [f64]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [f64]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut f64) {
+        self.lo.store_ptr(a);
+        self.hi.store_ptr(a.add(4));
+    }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [f64]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [f64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut f64) {
+        self.lo.store_ptr_aligned(a);
+        self.hi.store_ptr_aligned(a.add(4));
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [f64]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at_mut(a.len().min(4));
+        self.lo.store_partial(lo);
+        self.hi.store_partial(hi);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [f64]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut f64) {
+        self.lo.store_ptr_stream(a);
+        self.hi.store_ptr_stream(a.add(4));
+    }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::concat(self.lo.min(other.lo), self.hi.min(other.hi)) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::concat(self.lo.max(other.lo), self.hi.max(other.hi)) }
+
+    #[inline]
+    pub fn mul_add(self, mul: Self, add: Self) -> Self {
+        Self::concat(self.lo.mul_add(mul.lo, add.lo), self.hi.mul_add(mul.hi, add.hi))
+    }
+
+    #[inline]
+    pub fn mul_sub(self, mul: Self, sub: Self) -> Self {
+        Self::concat(self.lo.mul_sub(mul.lo, sub.lo), self.hi.mul_sub(mul.hi, sub.hi))
+    }
+
+    #[inline]
+    pub fn neg_mul_add(self, mul: Self, add: Self) -> Self {
+        Self::concat(self.lo.neg_mul_add(mul.lo, add.lo), self.hi.neg_mul_add(mul.hi, add.hi))
+    }
+
+    #[inline]
+    pub fn neg_mul_sub(self, mul: Self, sub: Self) -> Self {
+        Self::concat(self.lo.neg_mul_sub(mul.lo, sub.lo), self.hi.neg_mul_sub(mul.hi, sub.hi))
+    }
+
+    #[inline]
+    pub fn mul_addsub(self, mul: Self, addsub: Self) -> Self {
+        Self::concat(self.lo.mul_addsub(mul.lo, addsub.lo), self.hi.mul_addsub(mul.hi, addsub.hi))
+    }
+
+    #[inline]
+    pub fn abs(self) -> Self { Self::concat(self.lo.abs(), self.hi.abs()) }
+
+    #[inline]
+    pub fn copysign(self, sign: Self) -> Self { Self::concat(self.lo.copysign(sign.lo), self.hi.copysign(sign.hi)) }
+
+    #[inline]
+    pub fn signum(self) -> Self { Self::concat(self.lo.signum(), self.hi.signum()) }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { Self::concat(self.lo.andnot(other.lo), self.hi.andnot(other.hi)) }
+
+    #[inline]
+    pub fn sign_bit(self) -> Self { Self::concat(self.lo.sign_bit(), self.hi.sign_bit()) }
+
+    #[inline]
+    pub fn sign_bitmask(self) -> u8 { self.lo.sign_bitmask() | self.hi.sign_bitmask() << 4 }
+
+    #[inline]
+    pub fn is_nan(self) -> m64x8 { m64x8::concat(self.lo.is_nan(), self.hi.is_nan()) }
+
+    #[inline]
+    pub fn is_finite(self) -> m64x8 { m64x8::concat(self.lo.is_finite(), self.hi.is_finite()) }
+
+    #[inline]
+    pub fn sqrt(self) -> Self { Self::concat(self.lo.sqrt(), self.hi.sqrt()) }
+
+    #[inline]
+    pub fn rcp(self) -> Self { Self::concat(self.lo.rcp(), self.hi.rcp()) }
+
+    #[inline]
+    pub fn rcp_nr(self) -> Self { Self::concat(self.lo.rcp_nr(), self.hi.rcp_nr()) }
+
+    #[inline]
+    pub fn rsqrt(self) -> Self { Self::concat(self.lo.rsqrt(), self.hi.rsqrt()) }
+
+    #[inline]
+    pub fn rsqrt_nr(self) -> Self { Self::concat(self.lo.rsqrt_nr(), self.hi.rsqrt_nr()) }
+
+    #[inline]
+    pub fn sum(self) -> f64 { (self.lo + self.hi).sum() }
+
+    #[inline]
+    pub fn product(self) -> f64 { (self.lo * self.hi).product() }
+
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_min(self) -> f64 { self.lo.reduce_min().min(self.hi.reduce_min()) }
+
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    #[inline]
+    pub fn reduce_max(self) -> f64 { self.lo.reduce_max().max(self.hi.reduce_max()) }
+
+    /// Index of the first lane holding the minimum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum, ignoring NaN lanes. 0 if all lanes are NaN.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x8 { m64x8::concat(self.lo.eq(other.lo), self.hi.eq(other.hi)) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x8 { m64x8::concat(self.lo.ne(other.lo), self.hi.ne(other.hi)) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x8 { m64x8::concat(self.lo.lt(other.lo), self.hi.lt(other.hi)) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x8 { m64x8::concat(self.lo.le(other.lo), self.hi.le(other.hi)) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x8 { m64x8::concat(self.lo.gt(other.lo), self.hi.gt(other.hi)) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x8 { m64x8::concat(self.lo.ge(other.lo), self.hi.ge(other.hi)) }
+
+    #[inline]
+    pub fn select(mask: m64x8, a: Self, b: Self) -> Self {
+        Self::concat(f64x4::select(mask.lo(), a.lo, b.lo), f64x4::select(mask.hi(), a.hi, b.hi))
+    }
+
+    #[inline]
+    pub fn lo(self) -> f64x4 { self.lo }
+
+    #[inline]
+    pub fn hi(self) -> f64x4 { self.hi }
+
+    #[inline]
+    pub fn concat(lo: f64x4, hi: f64x4) -> Self { Self { lo, hi } }
+
+    #[inline]
+    pub fn to_array(self) -> [f64; 8] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[f64; 8] { unsafe { &*(self as *const Self as *const [f64; 8]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> f64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: f64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, f64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> f64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { self.to_raw_i64().swizzle::<S>().to_raw_f64() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        self.to_raw_i64().shuffle2::<S>(other.to_raw_i64()).to_raw_f64()
+    }
+}
+
+impl Default for f64x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0.0) }
+}
+
+impl PartialEq for f64x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { f64x8::eq(*self, *other).all() }
+}
+
+impl From<[f64; 8]> for f64x8 {
+    #[inline]
+    fn from(a: [f64; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<f64x8> for [f64; 8] {
+    #[inline]
+    fn from(v: f64x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for f64x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("f64x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for f64x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for f64x8 {
+    type Output = f64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &f64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for f64x8 {
+    type Item = f64;
+    type IntoIter = array::IntoIter<f64, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a f64x8 {
+    type Item = &'a f64;
+    type IntoIter = slice::Iter<'a, f64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl Mul<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn mul(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo * rhs.lo, self.hi * rhs.hi) }
+}
+
+impl MulAssign<f64x8> for f64x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: f64x8) { *self = *self * rhs }
+}
+
+impl Add<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn add(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo + rhs.lo, self.hi + rhs.hi) }
+}
+
+impl AddAssign for f64x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+}
+
+impl Sub<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn sub(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo - rhs.lo, self.hi - rhs.hi) }
+}
+
+impl SubAssign for f64x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+impl Div<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn div(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo / rhs.lo, self.hi / rhs.hi) }
+}
+
+impl DivAssign for f64x8 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) { *self = *self / rhs }
+}
+
+impl Neg for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn neg(self) -> Self::Output { Self::concat(-self.lo, -self.hi) }
+}
+
+impl BitAnd<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn bitand(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo & rhs.lo, self.hi & rhs.hi) }
+}
+
+impl BitAndAssign for f64x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn bitor(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo | rhs.lo, self.hi | rhs.hi) }
+}
+
+impl BitOrAssign for f64x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl BitXor<f64x8> for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn bitxor(self, rhs: f64x8) -> Self::Output { Self::concat(self.lo ^ rhs.lo, self.hi ^ rhs.hi) }
+}
+
+impl BitXorAssign for f64x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { *self = *self ^ rhs }
+}
+
+impl Not for f64x8 {
+    type Output = f64x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { Self::concat(!self.lo, !self.hi) }
 }
@@ -1,13 +1,24 @@
-#[cfg(target_arch = "x86")]
-use std::arch::x86::*;
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
-
-use crate::{i32x8, i64x4};
-use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::{
+    aligned::is_aligned, arch::*, detect::assert_supported, i32x4, i32x8, i64x2, i64x4, i8x32, lanes::fmt_lanes,
+    m16x16, m16x8, reduce::first_lane, swizzle::shift_left128, swizzle::shift_left256, swizzle::shift_right128,
+    swizzle::shift_right256, swizzle::shuffle128, swizzle::shuffle256, swizzle::swizzle128, swizzle::swizzle256,
+    swizzle::Broadcast, swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd, swizzle::InterleaveHi,
+    swizzle::InterleaveLo, swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight, swizzle::Swizzle, u16x16,
+    u16x8,
+};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Mul, MulAssign, Not,
+        Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct i16x16 {
     v: __m256i,
 }
@@ -32,17 +43,21 @@ impl i16x16 {
         v15: i16,
         v16: i16,
     ) -> Self {
+        assert_supported();
         Self {
             v: unsafe { _mm256_set_epi16(v16, v15, v14, v13, v12, v11, v10, v9, v8, v7, v6, v5, v4, v3, v2, v1) },
         }
     }
 
     #[inline]
-    pub fn splat(v: i16) -> i16x16 { unsafe { _mm256_set1_epi16(v) }.into() }
+    pub fn splat(v: i16) -> i16x16 {
+        assert_supported();
+        unsafe { _mm256_set1_epi16(v) }.into()
+    }
 
     #[inline]
     pub fn from_slice(a: &[i16]) -> Self {
-        if a.len() < 8 {
+        if a.len() < 16 {
             panic!("Slice too small!")
         }
 
@@ -53,7 +68,72 @@ impl i16x16 {
     pub unsafe fn from_slice_unchecked(a: &[i16]) -> Self { Self::from_ptr(a.as_ptr()) }
 
     #[inline]
-    pub unsafe fn from_ptr(a: *const i16) -> Self { _mm256_loadu_si256(a as *const __m256i).into() }
+    pub unsafe fn from_ptr(a: *const i16) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i16]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i16]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i16) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 16 and zeroes the rest, or 16 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i16]) -> Self {
+        if a.len() >= 16 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        // There are no masked loads of 8 and 16-bit lanes before AVX-512BW
+        let mut b = [0; 16];
+        b[..a.len()].copy_from_slice(a);
+        Self::from_slice(&b)
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i16]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i16) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
 
     #[inline]
     pub fn blend<const IMM: i32>(self, other: Self) -> Self {
@@ -66,12 +146,21 @@ impl i16x16 {
     #[inline]
     pub fn set<const IDX: i32>(self, v: i16) -> Self { unsafe { _mm256_insert_epi16::<IDX>(self.v, v) }.into() }
 
+    #[inline]
+    pub fn packs(self, other: i16x16) -> i8x32 { unsafe { _mm256_packs_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_i8(self) -> i8x32 { self.v.into() }
+
     #[inline]
     pub fn to_raw_i32(self) -> i32x8 { self.v.into() }
 
     #[inline]
     pub fn to_raw_i64(self) -> i64x4 { self.v.into() }
 
+    #[inline]
+    pub fn to_raw_u16(self) -> u16x16 { self.v.into() }
+
     #[inline]
     pub fn store(self, a: &mut [i16]) {
         if a.len() < 16 {
@@ -87,6 +176,77 @@ impl i16x16 {
     #[inline]
     pub unsafe fn store_ptr(self, a: *mut i16) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
 
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i16]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i16]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i16) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 16, or 16 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i16]) {
+        if a.len() >= 16 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let mut b = [0; 16];
+        self.store(&mut b);
+        a.copy_from_slice(&b[..a.len()]);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i16]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i16) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm256_slli_epi16::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm256_srli_epi16::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self { unsafe { _mm256_srai_epi16::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
     #[inline]
     pub fn min(self, other: Self) -> Self { unsafe { _mm256_min_epi16(self.v, other.v) }.into() }
 
@@ -94,7 +254,218 @@ impl i16x16 {
     pub fn max(self, other: Self) -> Self { unsafe { _mm256_max_epi16(self.v, other.v) }.into() }
 
     #[inline]
-    pub fn extract<const INDEX: i32>(self) -> i16 { unsafe { _mm256_extract_epi16(self.v, INDEX) as i16 } }
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> i16 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            let v = op(v, _mm_srli_si128::<8>(v));
+            let v = op(v, _mm_srli_si128::<4>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<2>(v))) as i16
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i16 { self.reduce(|a, b| unsafe { _mm_add_epi16(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i32 { i32x8::from(unsafe { _mm256_madd_epi16(self.v, _mm256_set1_epi16(1)) }).sum() }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i16 { self.reduce(|a, b| unsafe { _mm_mullo_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i16 { self.reduce(|a, b| unsafe { _mm_min_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i16 { self.reduce(|a, b| unsafe { _mm_max_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i16 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> i16 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i16 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m16x16 { unsafe { _mm256_cmpeq_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m16x16 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m16x16 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m16x16 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m16x16 { unsafe { _mm256_cmpgt_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m16x16 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m16x16, a: Self, b: Self) -> Self {
+        unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into()
+    }
+
+    #[inline]
+    pub fn lo(self) -> i16x8 { unsafe { _mm256_castsi256_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> i16x8 { unsafe { _mm256_extracti128_si256::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: i16x8, hi: i16x8) -> Self { unsafe { _mm256_set_m128i(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i16 { unsafe { _mm256_extract_epi16::<INDEX>(self.v) as i16 } }
+
+    #[inline]
+    pub fn to_array(self) -> [i16; 16] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i16; 16] { unsafe { &*(self as *const Self as *const [i16; 16]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i16 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i16) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i16> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i16>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 16>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 16>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<16>>() }
+
+    /// Lane `(i + N) % 16` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<16, N>>() }
+
+    /// Lane `(i - N) % 16` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<16, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 16.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<16, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 16.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<16, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<16, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<16>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<16>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<16>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<16>>(other) }
+}
+
+impl Default for i16x16 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i16x16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i16x16::eq(*self, *other).all() }
+}
+
+impl Eq for i16x16 {}
+
+impl Hash for i16x16 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i16; 16]> for i16x16 {
+    #[inline]
+    fn from(a: [i16; 16]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i16x16> for [i16; 16] {
+    #[inline]
+    fn from(v: i16x16) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i16x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i16x16").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i16x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i16x16 {
+    type Output = i16;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i16 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i16x16 {
+    type Item = i16;
+    type IntoIter = array::IntoIter<i16, 16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i16x16 {
+    type Item = &'a i16;
+    type IntoIter = slice::Iter<'a, i16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 
 impl From<__m256i> for i16x16 {
@@ -167,8 +538,53 @@ impl BitOrAssign for i16x16 {
     fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
 }
 
+impl BitXor<i16x16> for i16x16 {
+    type Output = i16x16;
+
+    #[inline]
+    fn bitxor(self, rhs: i16x16) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for i16x16 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i16x16 {
+    type Output = i16x16;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for i16x16 {
+    type Output = i16x16;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm256_sll_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for i16x16 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i16x16 {
+    type Output = i16x16;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm256_sra_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for i16x16 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+/// The 128-bit counterpart of [`i16x16`]. `packs`, `to_raw_i8` and `lo`/`hi`/`concat` are 256-bit only.
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct i16x8 {
     v: __m128i,
 }
@@ -176,16 +592,412 @@ pub struct i16x8 {
 impl i16x8 {
     #[inline]
     pub fn new(v1: i16, v2: i16, v3: i16, v4: i16, v5: i16, v6: i16, v7: i16, v8: i16) -> Self {
+        assert_supported();
         Self {
             v: unsafe { _mm_set_epi16(v8, v7, v6, v5, v4, v3, v2, v1) },
         }
     }
 
+    #[inline]
+    pub fn splat(v: i16) -> i16x8 {
+        assert_supported();
+        unsafe { _mm_set1_epi16(v) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[i16]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i16]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i16) -> Self {
+        assert_supported();
+        _mm_loadu_si128(a as *const __m128i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i16]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i16]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i16) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_si128(a as *const __m128i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i16]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        // There are no masked loads of 8 and 16-bit lanes before AVX-512BW
+        let mut b = [0; 8];
+        b[..a.len()].copy_from_slice(a);
+        Self::from_slice(&b)
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i16]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i16) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_stream_load_si128(a as *const __m128i).into()
+    }
+
     #[inline]
     pub fn get<const IDX: i32>(self) -> i16 { unsafe { _mm_extract_epi16::<IDX>(self.v) as i16 } }
 
     #[inline]
     pub fn set<const IDX: i32>(self, v: i16) -> Self { unsafe { _mm_insert_epi16::<IDX>(self.v, v as i32) }.into() }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        unsafe { _mm_blend_epi16::<IMM>(self.v, other.v) }.into()
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x4 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x2 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u16(self) -> u16x8 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [i16]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [i16]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut i16) { _mm_storeu_si128(a as *mut __m128i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i16]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i16]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i16) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_si128(a as *mut __m128i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i16]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let mut b = [0; 8];
+        self.store(&mut b);
+        a.copy_from_slice(&b[..a.len()]);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i16]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i16) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_si128(a as *mut __m128i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm_slli_epi16::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm_srli_epi16::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self { unsafe { _mm_srai_epi16::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_si128(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm_min_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm_max_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> i16 {
+        unsafe {
+            let v = self.v;
+            let v = op(v, _mm_srli_si128::<8>(v));
+            let v = op(v, _mm_srli_si128::<4>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<2>(v))) as i16
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i16 { self.reduce(|a, b| unsafe { _mm_add_epi16(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i32 { i32x4::from(unsafe { _mm_madd_epi16(self.v, _mm_set1_epi16(1)) }).sum() }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i16 { self.reduce(|a, b| unsafe { _mm_mullo_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i16 { self.reduce(|a, b| unsafe { _mm_min_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i16 { self.reduce(|a, b| unsafe { _mm_max_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i16 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> i16 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i16 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m16x8 { unsafe { _mm_cmpeq_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m16x8 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m16x8 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m16x8 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m16x8 { unsafe { _mm_cmpgt_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m16x8 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m16x8, a: Self, b: Self) -> Self { unsafe { _mm_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i16 { unsafe { _mm_extract_epi16::<INDEX>(self.v) as i16 } }
+
+    #[inline]
+    pub fn to_array(self) -> [i16; 8] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i16; 8] { unsafe { &*(self as *const Self as *const [i16; 8]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i16 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i16) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i16> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i16>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle128::<S, 8>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle128::<S, 8>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<8>>() }
+
+    /// Lane `(i + N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<8, N>>() }
+
+    /// Lane `(i - N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<8, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left128::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right128::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<8, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<8>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<8>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<8>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<8>>(other) }
+}
+
+impl Default for i16x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i16x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i16x8::eq(*self, *other).all() }
+}
+
+impl Eq for i16x8 {}
+
+impl Hash for i16x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i16; 8]> for i16x8 {
+    #[inline]
+    fn from(a: [i16; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i16x8> for [i16; 8] {
+    #[inline]
+    fn from(v: i16x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i16x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i16x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i16x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i16x8 {
+    type Output = i16;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i16 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i16x8 {
+    type Item = i16;
+    type IntoIter = array::IntoIter<i16, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i16x8 {
+    type Item = &'a i16;
+    type IntoIter = slice::Iter<'a, i16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 
 impl From<__m128i> for i16x8 {
@@ -233,3 +1045,70 @@ impl SubAssign for i16x8 {
     #[inline]
     fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
 }
+
+impl BitAnd<i16x8> for i16x8 {
+    type Output = i16x8;
+
+    #[inline]
+    fn bitand(self, rhs: i16x8) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for i16x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<i16x8> for i16x8 {
+    type Output = i16x8;
+
+    #[inline]
+    fn bitor(self, rhs: i16x8) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for i16x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<i16x8> for i16x8 {
+    type Output = i16x8;
+
+    #[inline]
+    fn bitxor(self, rhs: i16x8) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for i16x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i16x8 {
+    type Output = i16x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for i16x8 {
+    type Output = i16x8;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm_sll_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for i16x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i16x8 {
+    type Output = i16x8;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm_sra_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for i16x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
@@ -1,13 +1,27 @@
-#[cfg(target_arch = "x86")]
-use std::arch::x86::*;
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
-
-use crate::{f32x8, i16x16, i64x4};
-use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Mul, MulAssign, Sub, SubAssign};
+use crate::{
+    aligned::is_aligned, arch::*, bounds::OutOfBounds, detect::assert_supported, f32x16, f32x4, f32x8, f64x4, i16x16,
+    i16x8, i64x2, i64x4, i64x8, lanes::fmt_lanes, m32x16, m32x4, m32x8, partial::mask_epi32x4, partial::mask_epi32x8,
+    reduce::first_lane, scatter::scatter, swizzle::shift_left128, swizzle::shift_left256, swizzle::shift_right128,
+    swizzle::shift_right256, swizzle::shuffle128, swizzle::shuffle256, swizzle::shuffle512, swizzle::swizzle128,
+    swizzle::swizzle256, swizzle::swizzle512, swizzle::Broadcast, swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd,
+    swizzle::InterleaveHi, swizzle::InterleaveLo, swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight,
+    swizzle::Swizzle, u32x4, u32x8,
+};
+#[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+use crate::{avx512, detect::has_avx512f};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Mul, MulAssign, Not,
+        Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct i32x8 {
     v: __m256i,
 }
@@ -15,13 +29,17 @@ pub struct i32x8 {
 impl i32x8 {
     #[inline]
     pub fn new(v1: i32, v2: i32, v3: i32, v4: i32, v5: i32, v6: i32, v7: i32, v8: i32) -> Self {
+        assert_supported();
         Self {
             v: unsafe { _mm256_set_epi32(v8, v7, v6, v5, v4, v3, v2, v1) },
         }
     }
 
     #[inline]
-    pub fn splat(v: i32) -> i32x8 { unsafe { _mm256_set1_epi32(v) }.into() }
+    pub fn splat(v: i32) -> i32x8 {
+        assert_supported();
+        unsafe { _mm256_set1_epi32(v) }.into()
+    }
 
     #[inline]
     pub fn from_slice(a: &[i32]) -> Self {
@@ -36,7 +54,70 @@ impl i32x8 {
     pub unsafe fn from_slice_unchecked(a: &[i32]) -> Self { Self::from_ptr(a.as_ptr()) }
 
     #[inline]
-    pub unsafe fn from_ptr(a: *const i32) -> Self { _mm256_loadu_si256(a as *const __m256i).into() }
+    pub unsafe fn from_ptr(a: *const i32) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i32) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i32]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm256_maskload_epi32(a.as_ptr(), mask_epi32x8(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i32) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
 
     #[inline]
     pub unsafe fn gather_ptr<const SCALE: i32>(a: *const i32, idx: i32x8) -> Self {
@@ -48,6 +129,103 @@ impl i32x8 {
         Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
     }
 
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[i32], idx: i32x8) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<4>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[i32], idx: i32x8) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<4>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[i32], idx: i32x8, mask: m32x8, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<4>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const i32, idx: i32x8, mask: m32x8, default: Self) -> Self {
+        _mm256_mask_i32gather_epi32::<SCALE>(default.v, a, idx.into(), mask.into()).into()
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [i32], idx: i32x8) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [i32], idx: i32x8) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [i32], idx: i32x8, mask: m32x8) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<4>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut i32, idx: i32x8) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m32x8::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut i32, idx: i32x8, mask: m32x8) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i32scatter_epi32_256::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        let mut v = [0; 8];
+        let mut i = [0; 8];
+        self.store(&mut v);
+        idx.store(&mut i);
+        scatter(a, v, i, mask.to_bitmask() as u32, SCALE)
+    }
+
     #[inline]
     pub fn blend<const IMM: i32>(self, other: Self) -> Self {
         unsafe { _mm256_blend_epi32::<IMM>(self.v, other.v) }.into()
@@ -71,6 +249,9 @@ impl i32x8 {
     #[inline]
     pub fn to_raw_i64(self) -> i64x4 { self.v.into() }
 
+    #[inline]
+    pub fn to_raw_u32(self) -> u32x8 { self.v.into() }
+
     #[inline]
     pub fn to_f32(self) -> f32x8 { unsafe { _mm256_cvtepi32_ps(self.v) }.into() }
 
@@ -89,6 +270,75 @@ impl i32x8 {
     #[inline]
     pub unsafe fn store_ptr(self, a: *mut i32) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
 
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i32) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i32]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm256_maskstore_epi32(a.as_mut_ptr(), mask_epi32x8(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i32) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm256_slli_epi32::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm256_srli_epi32::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self { unsafe { _mm256_srai_epi32::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
     #[inline]
     pub fn min(self, other: Self) -> Self { unsafe { _mm256_min_epi32(self.v, other.v) }.into() }
 
@@ -96,7 +346,228 @@ impl i32x8 {
     pub fn max(self, other: Self) -> Self { unsafe { _mm256_max_epi32(self.v, other.v) }.into() }
 
     #[inline]
-    pub fn extract<const INDEX: i32>(self) -> i32 { unsafe { _mm256_extract_epi32(self.v, INDEX) } }
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> i32 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            let v = op(v, _mm_srli_si128::<8>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<4>(v)))
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i32 { self.reduce(|a, b| unsafe { _mm_add_epi32(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i64 {
+        i64x4::from(unsafe { _mm256_cvtepi32_epi64(self.lo().into()) }).sum() +
+            i64x4::from(unsafe { _mm256_cvtepi32_epi64(self.hi().into()) }).sum()
+    }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i32 { self.reduce(|a, b| unsafe { _mm_mullo_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i32 { self.reduce(|a, b| unsafe { _mm_min_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i32 { self.reduce(|a, b| unsafe { _mm_max_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i32 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> i32 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i32 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x8 { unsafe { _mm256_cmpeq_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x8 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x8 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x8 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x8 { unsafe { _mm256_cmpgt_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x8 { !other.gt(self) }
+
+    /// Lanes outside `0..len`, e.g. indices that would be out of bounds for a slice of length `len`.
+    #[inline]
+    pub fn out_of_bounds(self, len: usize) -> m32x8 {
+        if len > i32::MAX as usize {
+            return self.lt(Self::splat(0));
+        }
+
+        self.lt(Self::splat(0)) | self.ge(Self::splat(len as i32))
+    }
+
+    #[inline]
+    pub fn select(mask: m32x8, a: Self, b: Self) -> Self { unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn lo(self) -> i32x4 { unsafe { _mm256_castsi256_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> i32x4 { unsafe { _mm256_extracti128_si256::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: i32x4, hi: i32x4) -> Self { unsafe { _mm256_set_m128i(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i32 { unsafe { _mm256_extract_epi32::<INDEX>(self.v) } }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 8] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i32; 8] { unsafe { &*(self as *const Self as *const [i32; 8]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 8>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 8>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<8>>() }
+
+    /// Lane `(i + N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<8, N>>() }
+
+    /// Lane `(i - N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<8, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<8, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<8>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<8>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<8>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<8>>(other) }
+}
+
+impl Default for i32x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i32x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i32x8::eq(*self, *other).all() }
+}
+
+impl Eq for i32x8 {}
+
+impl Hash for i32x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i32; 8]> for i32x8 {
+    #[inline]
+    fn from(a: [i32; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i32x8> for [i32; 8] {
+    #[inline]
+    fn from(v: i32x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i32x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i32x8 {
+    type Output = i32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i32x8 {
+    type Item = i32;
+    type IntoIter = array::IntoIter<i32, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i32x8 {
+    type Item = &'a i32;
+    type IntoIter = slice::Iter<'a, i32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 
 impl From<__m256i> for i32x8 {
@@ -169,69 +640,1276 @@ impl BitOrAssign for i32x8 {
     fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
 }
 
-#[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
-pub struct i32x4 {
-    v: __m128i,
-}
+impl BitXor<i32x8> for i32x8 {
+    type Output = i32x8;
 
-impl i32x4 {
     #[inline]
-    pub fn new(v1: i32, v2: i32, v3: i32, v4: i32) -> Self {
-        Self {
-            v: unsafe { _mm_set_epi32(v4, v3, v2, v1) },
-        }
-    }
+    fn bitxor(self, rhs: i32x8) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
 
+impl BitXorAssign for i32x8 {
     #[inline]
-    pub fn get<const IDX: i32>(self) -> i32 { unsafe { _mm_extract_epi32::<IDX>(self.v) } }
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i32x8 {
+    type Output = i32x8;
 
     #[inline]
-    pub fn set<const IDX: i32>(self, v: i32) -> Self { unsafe { _mm_insert_epi32::<IDX>(self.v, v) }.into() }
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
 }
 
-impl From<__m128i> for i32x4 {
+impl Shl<i32> for i32x8 {
+    type Output = i32x8;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm256_sll_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for i32x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i32x8 {
+    type Output = i32x8;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm256_sra_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for i32x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<i32x8> for i32x8 {
+    type Output = i32x8;
+
+    #[inline]
+    fn shl(self, rhs: i32x8) -> Self::Output { unsafe { _mm256_sllv_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<i32x8> for i32x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32x8) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32x8> for i32x8 {
+    type Output = i32x8;
+
+    #[inline]
+    fn shr(self, rhs: i32x8) -> Self::Output { unsafe { _mm256_srav_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShrAssign<i32x8> for i32x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32x8) { self.v = (*self >> rhs).v }
+}
+
+/// The 128-bit counterpart of [`i32x8`]. Gathers, scatters and `lo`/`hi`/`concat` are 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct i32x4 {
+    v: __m128i,
+}
+
+impl i32x4 {
+    #[inline]
+    pub fn new(v1: i32, v2: i32, v3: i32, v4: i32) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm_set_epi32(v4, v3, v2, v1) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: i32) -> i32x4 {
+        assert_supported();
+        unsafe { _mm_set1_epi32(v) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[i32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i32]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i32) -> Self {
+        assert_supported();
+        _mm_loadu_si128(a as *const __m128i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i32) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_si128(a as *const __m128i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 4 and zeroes the rest, or 4 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i32]) -> Self {
+        if a.len() >= 4 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm_maskload_epi32(a.as_ptr(), mask_epi32x4(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i32) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_stream_load_si128(a as *const __m128i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> i32 { unsafe { _mm_extract_epi32::<IDX>(self.v) } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: i32) -> Self { unsafe { _mm_insert_epi32::<IDX>(self.v, v) }.into() }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        unsafe { _mm_blend_epi32::<IMM>(self.v, other.v) }.into()
+    }
+
+    #[inline]
+    pub fn packs(self, other: i32x4) -> i16x8 { unsafe { _mm_packs_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x4 { unsafe { _mm_castsi128_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn to_f32(self) -> f32x4 { unsafe { _mm_cvtepi32_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn to_f64(self) -> f64x4 { unsafe { _mm256_cvtepi32_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_i16(self) -> i16x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x2 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u32(self) -> u32x4 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [i32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [i32]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut i32) { _mm_storeu_si128(a as *mut __m128i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i32) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_si128(a as *mut __m128i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 4, or 4 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i32]) {
+        if a.len() >= 4 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm_maskstore_epi32(a.as_mut_ptr(), mask_epi32x4(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i32) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_si128(a as *mut __m128i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm_slli_epi32::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm_srli_epi32::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self { unsafe { _mm_srai_epi32::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_si128(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm_min_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm_max_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> i32 {
+        unsafe {
+            let v = self.v;
+            let v = op(v, _mm_srli_si128::<8>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<4>(v)))
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i32 { self.reduce(|a, b| unsafe { _mm_add_epi32(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i64 {
+        i64x2::from(unsafe { _mm_cvtepi32_epi64(self.v) }).sum() +
+            i64x2::from(unsafe { _mm_cvtepi32_epi64(_mm_srli_si128::<8>(self.v)) }).sum()
+    }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i32 { self.reduce(|a, b| unsafe { _mm_mullo_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i32 { self.reduce(|a, b| unsafe { _mm_min_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i32 { self.reduce(|a, b| unsafe { _mm_max_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i32 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> i32 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i32 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x4 { unsafe { _mm_cmpeq_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x4 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x4 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x4 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x4 { unsafe { _mm_cmpgt_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x4 { !other.gt(self) }
+
+    /// Lanes outside `0..len`, e.g. indices that would be out of bounds for a slice of length `len`.
+    #[inline]
+    pub fn out_of_bounds(self, len: usize) -> m32x4 {
+        if len > i32::MAX as usize {
+            return self.lt(Self::splat(0));
+        }
+
+        self.lt(Self::splat(0)) | self.ge(Self::splat(len as i32))
+    }
+
+    #[inline]
+    pub fn select(mask: m32x4, a: Self, b: Self) -> Self { unsafe { _mm_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i32 { unsafe { _mm_extract_epi32::<INDEX>(self.v) } }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 4] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i32; 4] { unsafe { &*(self as *const Self as *const [i32; 4]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle128::<S, 4>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle128::<S, 4>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<4>>() }
+
+    /// Lane `(i + N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<4, N>>() }
+
+    /// Lane `(i - N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<4, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left128::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right128::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<4, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<4>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<4>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<4>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<4>>(other) }
+}
+
+impl Default for i32x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i32x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i32x4::eq(*self, *other).all() }
+}
+
+impl Eq for i32x4 {}
+
+impl Hash for i32x4 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i32; 4]> for i32x4 {
+    #[inline]
+    fn from(a: [i32; 4]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i32x4> for [i32; 4] {
+    #[inline]
+    fn from(v: i32x4) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i32x4").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i32x4 {
+    type Output = i32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i32x4 {
+    type Item = i32;
+    type IntoIter = array::IntoIter<i32, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i32x4 {
+    type Item = &'a i32;
+    type IntoIter = slice::Iter<'a, i32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128i> for i32x4 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<i32x4> for __m128i {
+    #[inline]
+    fn from(v: i32x4) -> Self { v.v }
+}
+
+impl Mul<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn mul(self, rhs: i32x4) -> Self::Output { unsafe { _mm_mullo_epi32(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<i32x4> for i32x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32x4) { self.v = (*self * rhs).v }
+}
+
+impl Add<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn add(self, rhs: i32x4) -> Self::Output { unsafe { _mm_add_epi32(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for i32x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn sub(self, rhs: i32x4) -> Self::Output { unsafe { _mm_sub_epi32(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for i32x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn bitand(self, rhs: i32x4) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for i32x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn bitor(self, rhs: i32x4) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for i32x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn bitxor(self, rhs: i32x4) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for i32x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm_sll_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for i32x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm_sra_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for i32x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn shl(self, rhs: i32x4) -> Self::Output { unsafe { _mm_sllv_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<i32x4> for i32x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32x4) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32x4> for i32x4 {
+    type Output = i32x4;
+
+    #[inline]
+    fn shr(self, rhs: i32x4) -> Self::Output { unsafe { _mm_srav_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShrAssign<i32x4> for i32x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32x4) { self.v = (*self >> rhs).v }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct i32x16 {
+    lo: i32x8,
+    hi: i32x8,
+}
+
+impl i32x16 {
+    #[inline]
+    pub fn new(
+        v1: i32,
+        v2: i32,
+        v3: i32,
+        v4: i32,
+        v5: i32,
+        v6: i32,
+        v7: i32,
+        v8: i32,
+        v9: i32,
+        v10: i32,
+        v11: i32,
+        v12: i32,
+        v13: i32,
+        v14: i32,
+        v15: i32,
+        v16: i32,
+    ) -> Self {
+        Self::concat(i32x8::new(v1, v2, v3, v4, v5, v6, v7, v8), i32x8::new(v9, v10, v11, v12, v13, v14, v15, v16))
+    }
+
+    #[inline]
+    pub fn splat(v: i32) -> Self { Self::concat(i32x8::splat(v), i32x8::splat(v)) }
+
+    #[inline]
+    pub fn from_slice(a: &[i32]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i32]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i32) -> Self { Self::concat(i32x8::from_ptr(a), i32x8::from_ptr(a.add(8))) }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i32]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i32) -> Self {
+        Self::concat(i32x8::from_ptr_aligned(a), i32x8::from_ptr_aligned(a.add(8)))
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 16 and zeroes the rest, or 16 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i32]) -> Self {
+        if a.len() >= 16 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at(a.len().min(8));
+        Self::concat(i32x8::load_partial(lo), i32x8::load_partial(hi))
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i32]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i32) -> Self {
+        Self::concat(i32x8::from_ptr_stream(a), i32x8::from_ptr_stream(a.add(8)))
+    }
+
+    #[inline]
+    pub unsafe fn gather_ptr<const SCALE: i32>(a: *const i32, idx: i32x16) -> Self {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::i32gather_epi32::<SCALE>(a, idx);
+        }
+
+        Self::concat(i32x8::gather_ptr::<SCALE>(a, idx.lo()), i32x8::gather_ptr::<SCALE>(a, idx.hi()))
+    }
+
+    #[inline]
+    pub unsafe fn gather_unchecked<const SCALE: i32>(a: &[i32], idx: i32x16) -> Self {
+        Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[i32], idx: i32x16) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<4>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[i32], idx: i32x16) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<4>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[i32], idx: i32x16, mask: m32x16, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<4>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
     #[inline]
-    fn from(v: __m128i) -> Self { Self { v } }
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const i32, idx: i32x16, mask: m32x16, default: Self) -> Self {
+        Self::concat(
+            i32x8::gather_masked_ptr::<SCALE>(a, idx.lo(), mask.lo(), default.lo),
+            i32x8::gather_masked_ptr::<SCALE>(a, idx.hi(), mask.hi(), default.hi),
+        )
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [i32], idx: i32x16) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [i32], idx: i32x16) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<4>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [i32], idx: i32x16, mask: m32x16) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<4>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut i32, idx: i32x16) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m32x16::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut i32, idx: i32x16, mask: m32x16) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i32scatter_epi32::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        self.lo.scatter_masked_ptr::<SCALE>(a, idx.lo(), mask.lo());
+        self.hi.scatter_masked_ptr::<SCALE>(a, idx.hi(), mask.hi())
+    }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        Self::select(m32x16::from_bitmask(IMM as u16), other, self)
+    }
+
+    #[inline]
+    pub fn permute_var(self, idx: i32x16) -> Self { self.to_raw_f32().permute_var(idx).to_raw_i32() }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x16 { f32x16::concat(self.lo.to_raw_f32(), self.hi.to_raw_f32()) }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x8 { i64x8::concat(self.lo.to_raw_i64(), self.hi.to_raw_i64()) }
+
+    #[inline]
+    pub fn to_f32(self) -> f32x16 { f32x16::concat(self.lo.to_f32(), self.hi.to_f32()) }
+
+    #[inline]
+    pub fn store(self, a: &mut [i32]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [i32]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut i32) {
+        self.lo.store_ptr(a);
+        self.hi.store_ptr(a.add(8));
+    }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i32]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i32) {
+        self.lo.store_ptr_aligned(a);
+        self.hi.store_ptr_aligned(a.add(8));
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 16, or 16 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i32]) {
+        if a.len() >= 16 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at_mut(a.len().min(8));
+        self.lo.store_partial(lo);
+        self.hi.store_partial(hi);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i32]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i32) {
+        self.lo.store_ptr_stream(a);
+        self.hi.store_ptr_stream(a.add(8));
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { Self::concat(self.lo.shl::<N>(), self.hi.shl::<N>()) }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self {
+        Self::concat(self.lo.shr_logical::<N>(), self.hi.shr_logical::<N>())
+    }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self { Self::concat(self.lo.shr_arith::<N>(), self.hi.shr_arith::<N>()) }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { Self::concat(self.lo.andnot(other.lo), self.hi.andnot(other.hi)) }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::concat(self.lo.min(other.lo), self.hi.min(other.hi)) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::concat(self.lo.max(other.lo), self.hi.max(other.hi)) }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i32 { (self.lo + self.hi).sum() }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i64 { self.lo.sum_widening() + self.hi.sum_widening() }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i32 { self.lo.product().wrapping_mul(self.hi.product()) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i32 { self.lo.min(self.hi).reduce_min() }
+
+    #[inline]
+    pub fn reduce_max(self) -> i32 { self.lo.max(self.hi).reduce_max() }
+
+    #[inline]
+    pub fn reduce_and(self) -> i32 { (self.lo & self.hi).reduce_and() }
+
+    #[inline]
+    pub fn reduce_or(self) -> i32 { (self.lo | self.hi).reduce_or() }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i32 { (self.lo ^ self.hi).reduce_xor() }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x16 { m32x16::concat(self.lo.eq(other.lo), self.hi.eq(other.hi)) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x16 { m32x16::concat(self.lo.ne(other.lo), self.hi.ne(other.hi)) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x16 { m32x16::concat(self.lo.lt(other.lo), self.hi.lt(other.hi)) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x16 { m32x16::concat(self.lo.le(other.lo), self.hi.le(other.hi)) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x16 { m32x16::concat(self.lo.gt(other.lo), self.hi.gt(other.hi)) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x16 { m32x16::concat(self.lo.ge(other.lo), self.hi.ge(other.hi)) }
+
+    /// Lanes outside `0..len`, e.g. indices that would be out of bounds for a slice of length `len`.
+    #[inline]
+    pub fn out_of_bounds(self, len: usize) -> m32x16 {
+        m32x16::concat(self.lo.out_of_bounds(len), self.hi.out_of_bounds(len))
+    }
+
+    #[inline]
+    pub fn select(mask: m32x16, a: Self, b: Self) -> Self {
+        Self::concat(i32x8::select(mask.lo(), a.lo, b.lo), i32x8::select(mask.hi(), a.hi, b.hi))
+    }
+
+    #[inline]
+    pub fn lo(self) -> i32x8 { self.lo }
+
+    #[inline]
+    pub fn hi(self) -> i32x8 { self.hi }
+
+    #[inline]
+    pub fn concat(lo: i32x8, hi: i32x8) -> Self { Self { lo, hi } }
+
+    #[inline]
+    pub fn to_array(self) -> [i32; 16] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i32; 16] { unsafe { &*(self as *const Self as *const [i32; 16]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self {
+        let [lo, hi] = unsafe { swizzle512::<S, 16>([self.lo.into(), self.hi.into()]) };
+        Self::concat(i32x8::from(lo), i32x8::from(hi))
+    }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        let a = [self.lo.into(), self.hi.into()];
+        let b = [other.lo.into(), other.hi.into()];
+        let [lo, hi] = unsafe { shuffle512::<S, 16>(a, b) };
+        Self::concat(i32x8::from(lo), i32x8::from(hi))
+    }
 }
 
-impl From<i32x4> for __m128i {
+impl Default for i32x16 {
     #[inline]
-    fn from(v: i32x4) -> Self { v.v }
+    fn default() -> Self { Self::splat(0) }
 }
 
-impl Mul<i32x4> for i32x4 {
-    type Output = i32x4;
+impl PartialEq for i32x16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i32x16::eq(*self, *other).all() }
+}
 
+impl Eq for i32x16 {}
+
+impl Hash for i32x16 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i32; 16]> for i32x16 {
     #[inline]
-    fn mul(self, rhs: i32x4) -> Self::Output { unsafe { _mm_mullo_epi32(self.v, rhs.v) }.into() }
+    fn from(a: [i32; 16]) -> Self { Self::from_slice(&a) }
 }
 
-impl MulAssign<i32x4> for i32x4 {
+impl From<i32x16> for [i32; 16] {
     #[inline]
-    fn mul_assign(&mut self, rhs: i32x4) { self.v = (*self * rhs).v }
+    fn from(v: i32x16) -> Self { v.to_array() }
 }
 
-impl Add<i32x4> for i32x4 {
-    type Output = i32x4;
+impl fmt::Debug for i32x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i32x16").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i32x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i32x16 {
+    type Output = i32;
 
     #[inline]
-    fn add(self, rhs: i32x4) -> Self::Output { unsafe { _mm_add_epi32(self.v, rhs.v) }.into() }
+    fn index(&self, i: usize) -> &i32 { &self.as_array()[i] }
 }
 
-impl AddAssign for i32x4 {
+impl IntoIterator for i32x16 {
+    type Item = i32;
+    type IntoIter = array::IntoIter<i32, 16>;
+
     #[inline]
-    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
 }
 
-impl Sub<i32x4> for i32x4 {
-    type Output = i32x4;
+impl<'a> IntoIterator for &'a i32x16 {
+    type Item = &'a i32;
+    type IntoIter = slice::Iter<'a, i32>;
 
     #[inline]
-    fn sub(self, rhs: i32x4) -> Self::Output { unsafe { _mm_sub_epi32(self.v, rhs.v) }.into() }
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
 }
 
-impl SubAssign for i32x4 {
+impl Mul<i32x16> for i32x16 {
+    type Output = i32x16;
+
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+    fn mul(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo * rhs.lo, self.hi * rhs.hi) }
+}
+
+impl MulAssign<i32x16> for i32x16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: i32x16) { *self = *self * rhs }
+}
+
+impl Add<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn add(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo + rhs.lo, self.hi + rhs.hi) }
+}
+
+impl AddAssign for i32x16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+}
+
+impl Sub<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn sub(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo - rhs.lo, self.hi - rhs.hi) }
+}
+
+impl SubAssign for i32x16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+impl BitAnd<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn bitand(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo & rhs.lo, self.hi & rhs.hi) }
+}
+
+impl BitAndAssign for i32x16 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn bitor(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo | rhs.lo, self.hi | rhs.hi) }
+}
+
+impl BitOrAssign for i32x16 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl BitXor<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn bitxor(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo ^ rhs.lo, self.hi ^ rhs.hi) }
+}
+
+impl BitXorAssign for i32x16 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { *self = *self ^ rhs }
+}
+
+impl Not for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn not(self) -> Self::Output { Self::concat(!self.lo, !self.hi) }
+}
+
+impl Shl<i32> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { Self::concat(self.lo << rhs, self.hi << rhs) }
+}
+
+impl ShlAssign<i32> for i32x16 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { *self = *self << rhs }
+}
+
+impl Shr<i32> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { Self::concat(self.lo >> rhs, self.hi >> rhs) }
+}
+
+impl ShrAssign<i32> for i32x16 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { *self = *self >> rhs }
+}
+
+impl Shl<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn shl(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo << rhs.lo, self.hi << rhs.hi) }
+}
+
+impl ShlAssign<i32x16> for i32x16 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32x16) { *self = *self << rhs }
+}
+
+impl Shr<i32x16> for i32x16 {
+    type Output = i32x16;
+
+    #[inline]
+    fn shr(self, rhs: i32x16) -> Self::Output { Self::concat(self.lo >> rhs.lo, self.hi >> rhs.hi) }
+}
+
+impl ShrAssign<i32x16> for i32x16 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32x16) { *self = *self >> rhs }
 }
@@ -1,13 +1,27 @@
-#[cfg(target_arch = "x86")]
-use std::arch::x86::*;
-#[cfg(target_arch = "x86_64")]
-use std::arch::x86_64::*;
-
-use crate::{f32x8, i16x16};
-use std::ops::{Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+use crate::{
+    aligned::is_aligned, arch::*, bounds::OutOfBounds, detect::assert_supported, f32x4, f32x8, f64x2, f64x4, f64x8,
+    i16x16, i16x8, i32x16, i32x4, i32x8, lanes::fmt_lanes, m64x2, m64x4, m64x8, partial::mask_epi64x2,
+    partial::mask_epi64x4, reduce::first_lane, scatter::scatter, swizzle::shift_left128, swizzle::shift_left256,
+    swizzle::shift_right128, swizzle::shift_right256, swizzle::shuffle128, swizzle::shuffle256, swizzle::shuffle512,
+    swizzle::swizzle128, swizzle::swizzle256, swizzle::swizzle512, swizzle::Broadcast, swizzle::DeinterleaveEven,
+    swizzle::DeinterleaveOdd, swizzle::InterleaveHi, swizzle::InterleaveLo, swizzle::Reverse, swizzle::RotateLeft,
+    swizzle::RotateRight, swizzle::Swizzle, u64x2, u64x4,
+};
+#[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+use crate::{avx512, detect::has_avx512f};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not, Shl, ShlAssign,
+        Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
 
 #[allow(non_camel_case_types)]
-#[derive(Debug, Copy, Clone)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
 pub struct i64x4 {
     v: __m256i,
 }
@@ -15,11 +29,96 @@ pub struct i64x4 {
 impl i64x4 {
     #[inline]
     pub fn new(v1: i64, v2: i64, v3: i64, v4: i64) -> Self {
+        assert_supported();
         Self {
             v: unsafe { _mm256_set_epi64x(v4, v3, v2, v1) },
         }
     }
 
+    #[inline]
+    pub fn splat(v: i64) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_epi64x(v) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[i64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i64) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i64) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 4 and zeroes the rest, or 4 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i64]) -> Self {
+        if a.len() >= 4 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm256_maskload_epi64(a.as_ptr(), mask_epi64x4(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i64) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
+
     #[inline]
     pub fn get<const IDX: i32>(self) -> i64 { unsafe { _mm256_extract_epi64::<IDX>(self.v) } }
 
@@ -39,12 +138,118 @@ impl i64x4 {
         Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
     }
 
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[i64], idx: i64x4) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<8>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[i64], idx: i64x4) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<8>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[i64], idx: i64x4, mask: m64x4, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<8>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const i64, idx: i64x4, mask: m64x4, default: Self) -> Self {
+        _mm256_mask_i64gather_epi64::<SCALE>(default.v, a, idx.into(), mask.into()).into()
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [i64], idx: i64x4) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<8>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [i64], idx: i64x4) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<8>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [i64], idx: i64x4, mask: m64x4) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<8>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut i64, idx: i64x4) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m64x4::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut i64, idx: i64x4, mask: m64x4) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i64scatter_epi64_256::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        let mut v = [0; 4];
+        let mut i = [0; 4];
+        self.store(&mut v);
+        idx.store(&mut i);
+        scatter(a, v, i, mask.to_bitmask() as u32, SCALE)
+    }
+
     #[inline]
     pub fn to_raw_i16(self) -> i16x16 { self.v.into() }
 
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x8 { self.v.into() }
+
     #[inline]
     pub fn to_raw_f32(self) -> f32x8 { unsafe { _mm256_castsi256_ps(self.v) }.into() }
 
+    #[inline]
+    pub fn to_raw_f64(self) -> f64x4 { unsafe { _mm256_castsi256_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_u64(self) -> u64x4 { self.v.into() }
+
     #[inline]
     pub fn store(self, a: &mut [i64]) {
         if a.len() < 4 {
@@ -60,64 +265,1572 @@ impl i64x4 {
     #[inline]
     pub unsafe fn store_ptr(self, a: *mut i64) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
 
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
     #[inline]
-    pub fn extract<const INDEX: i32>(self) -> i64 { unsafe { _mm256_extract_epi64(self.v, INDEX) } }
-}
+    pub fn store_aligned(self, a: &mut [i64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
 
-impl From<__m256i> for i64x4 {
     #[inline]
-    fn from(v: __m256i) -> Self { Self { v } }
-}
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
 
-impl From<i64x4> for __m256i {
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
     #[inline]
-    fn from(v: i64x4) -> Self { v.v }
-}
+    pub unsafe fn store_ptr_aligned(self, a: *mut i64) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
 
-impl Add<i64x4> for i64x4 {
-    type Output = i64x4;
+    /// Stores the first `a.len()` lanes into a slice shorter than 4, or 4 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i64]) {
+        if a.len() >= 4 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm256_maskstore_epi64(a.as_mut_ptr(), mask_epi64x4(a.len()), self.v) }
+    }
 
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
     #[inline]
-    fn add(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_add_epi64(self.v, rhs.v) }.into() }
+    pub fn store_stream(self, a: &mut [i64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i64) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm256_slli_epi64::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm256_srli_epi64::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self {
+        // AVX2 has no srai_epi64, flip negative lanes so zeros are shifted in and flip the result back
+        let sign = self.sign_mask();
+        (self ^ sign).shr_logical::<N>() ^ sign
+    }
+
+    #[inline]
+    fn sign_mask(self) -> Self { unsafe { _mm256_cmpgt_epi64(_mm256_setzero_si256(), self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::select(self.lt(other), self, other) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::select(self.gt(other), self, other) }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> i64 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            _mm_cvtsi128_si64(op(v, _mm_srli_si128::<8>(v)))
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i64 { self.reduce(|a, b| unsafe { _mm_add_epi64(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i128 { self.lo().sum_widening() + self.hi().sum_widening() }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i64 { self.lo().product().wrapping_mul(self.hi().product()) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i64 { self.reduce(|a, b| i64x2::from(a).min(b.into()).into()) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i64 { self.reduce(|a, b| i64x2::from(a).max(b.into()).into()) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i64 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> i64 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i64 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x4 { unsafe { _mm256_cmpeq_epi64(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x4 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x4 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x4 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x4 { unsafe { _mm256_cmpgt_epi64(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x4 { !other.gt(self) }
+
+    /// Lanes outside `0..len`, e.g. indices that would be out of bounds for a slice of length `len`.
+    #[inline]
+    pub fn out_of_bounds(self, len: usize) -> m64x4 { self.lt(Self::splat(0)) | self.ge(Self::splat(len as i64)) }
+
+    #[inline]
+    pub fn select(mask: m64x4, a: Self, b: Self) -> Self { unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn lo(self) -> i64x2 { unsafe { _mm256_castsi256_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> i64x2 { unsafe { _mm256_extracti128_si256::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: i64x2, hi: i64x2) -> Self { unsafe { _mm256_set_m128i(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i64 { unsafe { _mm256_extract_epi64::<INDEX>(self.v) } }
+
+    #[inline]
+    pub fn to_array(self) -> [i64; 4] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i64; 4] { unsafe { &*(self as *const Self as *const [i64; 4]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 4>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 4>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<4>>() }
+
+    /// Lane `(i + N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<4, N>>() }
+
+    /// Lane `(i - N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<4, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<4, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<4>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<4>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<4>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<4>>(other) }
 }
 
-impl AddAssign for i64x4 {
+impl Default for i64x4 {
     #[inline]
-    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+    fn default() -> Self { Self::splat(0) }
 }
 
-impl Sub<i64x4> for i64x4 {
-    type Output = i64x4;
+impl PartialEq for i64x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i64x4::eq(*self, *other).all() }
+}
 
+impl Eq for i64x4 {}
+
+impl Hash for i64x4 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i64; 4]> for i64x4 {
     #[inline]
-    fn sub(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_sub_epi64(self.v, rhs.v) }.into() }
+    fn from(a: [i64; 4]) -> Self { Self::from_slice(&a) }
 }
 
-impl SubAssign for i64x4 {
+impl From<i64x4> for [i64; 4] {
     #[inline]
-    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+    fn from(v: i64x4) -> Self { v.to_array() }
 }
 
-impl BitAnd<i64x4> for i64x4 {
-    type Output = i64x4;
+impl fmt::Debug for i64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i64x4").field(self.as_array()).finish() }
+}
 
-    #[inline]
-    fn bitand(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+impl fmt::Display for i64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
 }
 
-impl BitAndAssign for i64x4 {
+impl Index<usize> for i64x4 {
+    type Output = i64;
+
     #[inline]
-    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+    fn index(&self, i: usize) -> &i64 { &self.as_array()[i] }
 }
 
-impl BitOr<i64x4> for i64x4 {
-    type Output = i64x4;
+impl IntoIterator for i64x4 {
+    type Item = i64;
+    type IntoIter = array::IntoIter<i64, 4>;
 
     #[inline]
-    fn bitor(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
 }
 
-impl BitOrAssign for i64x4 {
+impl<'a> IntoIterator for &'a i64x4 {
+    type Item = &'a i64;
+    type IntoIter = slice::Iter<'a, i64>;
+
     #[inline]
-    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256i> for i64x4 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<i64x4> for __m256i {
+    #[inline]
+    fn from(v: i64x4) -> Self { v.v }
+}
+
+impl Add<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn add(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_add_epi64(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for i64x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn sub(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_sub_epi64(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for i64x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn bitand(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for i64x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn bitor(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for i64x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn bitxor(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for i64x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm256_sll_epi64(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for i64x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output {
+        let sign = self.sign_mask();
+        i64x4::from(unsafe { _mm256_srl_epi64((self ^ sign).v, _mm_cvtsi32_si128(rhs)) }) ^ sign
+    }
+}
+
+impl ShrAssign<i32> for i64x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn shl(self, rhs: i64x4) -> Self::Output { unsafe { _mm256_sllv_epi64(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<i64x4> for i64x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64x4) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i64x4> for i64x4 {
+    type Output = i64x4;
+
+    #[inline]
+    fn shr(self, rhs: i64x4) -> Self::Output {
+        let sign = self.sign_mask();
+        i64x4::from(unsafe { _mm256_srlv_epi64((self ^ sign).v, rhs.v) }) ^ sign
+    }
+}
+
+impl ShrAssign<i64x4> for i64x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64x4) { self.v = (*self >> rhs).v }
+}
+
+/// The 128-bit counterpart of [`i64x4`]. Gathers, scatters, `permute` and `lo`/`hi`/`concat` are 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct i64x2 {
+    v: __m128i,
+}
+
+impl i64x2 {
+    #[inline]
+    pub fn new(v1: i64, v2: i64) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm_set_epi64x(v2, v1) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: i64) -> i64x2 {
+        assert_supported();
+        unsafe { _mm_set1_epi64x(v) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[i64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i64) -> Self {
+        assert_supported();
+        _mm_loadu_si128(a as *const __m128i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i64) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_si128(a as *const __m128i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 2 and zeroes the rest, or 2 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i64]) -> Self {
+        if a.len() >= 2 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm_maskload_epi64(a.as_ptr(), mask_epi64x2(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i64) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_stream_load_si128(a as *const __m128i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> i64 { unsafe { _mm_extract_epi64::<IDX>(self.v) } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: i64) -> Self { unsafe { _mm_insert_epi64::<IDX>(self.v, v) }.into() }
+
+    #[inline]
+    pub fn to_raw_i16(self) -> i16x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x4 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x4 { unsafe { _mm_castsi128_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_f64(self) -> f64x2 { unsafe { _mm_castsi128_pd(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_u64(self) -> u64x2 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [i64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [i64]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut i64) { _mm_storeu_si128(a as *mut __m128i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i64) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_si128(a as *mut __m128i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 2, or 2 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i64]) {
+        if a.len() >= 2 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm_maskstore_epi64(a.as_mut_ptr(), mask_epi64x2(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i64) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_si128(a as *mut __m128i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm_slli_epi64::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm_srli_epi64::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self {
+        // AVX2 has no srai_epi64, flip negative lanes so zeros are shifted in and flip the result back
+        let sign = self.sign_mask();
+        (self ^ sign).shr_logical::<N>() ^ sign
+    }
+
+    #[inline]
+    fn sign_mask(self) -> Self { unsafe { _mm_cmpgt_epi64(_mm_setzero_si128(), self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_si128(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::select(self.lt(other), self, other) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::select(self.gt(other), self, other) }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i64 { self.get::<0>().wrapping_add(self.get::<1>()) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i128 { self.get::<0>() as i128 + self.get::<1>() as i128 }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i64 { self.get::<0>().wrapping_mul(self.get::<1>()) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i64 { self.get::<0>().min(self.get::<1>()) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i64 { self.get::<0>().max(self.get::<1>()) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i64 { self.get::<0>() & self.get::<1>() }
+
+    #[inline]
+    pub fn reduce_or(self) -> i64 { self.get::<0>() | self.get::<1>() }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i64 { self.get::<0>() ^ self.get::<1>() }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x2 { unsafe { _mm_cmpeq_epi64(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x2 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x2 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x2 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x2 { unsafe { _mm_cmpgt_epi64(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x2 { !other.gt(self) }
+
+    /// Lanes outside `0..len`, e.g. indices that would be out of bounds for a slice of length `len`.
+    #[inline]
+    pub fn out_of_bounds(self, len: usize) -> m64x2 { self.lt(Self::splat(0)) | self.ge(Self::splat(len as i64)) }
+
+    #[inline]
+    pub fn select(mask: m64x2, a: Self, b: Self) -> Self { unsafe { _mm_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i64 { unsafe { _mm_extract_epi64::<INDEX>(self.v) } }
+
+    #[inline]
+    pub fn to_array(self) -> [i64; 2] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i64; 2] { unsafe { &*(self as *const Self as *const [i64; 2]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle128::<S, 2>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle128::<S, 2>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<2>>() }
+
+    /// Lane `(i + N) % 2` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<2, N>>() }
+
+    /// Lane `(i - N) % 2` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<2, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 2.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left128::<2, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 2.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right128::<2, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<2, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<2>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<2>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<2>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<2>>(other) }
+}
+
+impl Default for i64x2 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i64x2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i64x2::eq(*self, *other).all() }
+}
+
+impl Eq for i64x2 {}
+
+impl Hash for i64x2 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i64; 2]> for i64x2 {
+    #[inline]
+    fn from(a: [i64; 2]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i64x2> for [i64; 2] {
+    #[inline]
+    fn from(v: i64x2) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i64x2").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i64x2 {
+    type Output = i64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i64x2 {
+    type Item = i64;
+    type IntoIter = array::IntoIter<i64, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i64x2 {
+    type Item = &'a i64;
+    type IntoIter = slice::Iter<'a, i64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128i> for i64x2 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<i64x2> for __m128i {
+    #[inline]
+    fn from(v: i64x2) -> Self { v.v }
+}
+
+impl Add<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn add(self, rhs: i64x2) -> Self::Output { unsafe { _mm_add_epi64(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for i64x2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn sub(self, rhs: i64x2) -> Self::Output { unsafe { _mm_sub_epi64(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for i64x2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn bitand(self, rhs: i64x2) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for i64x2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn bitor(self, rhs: i64x2) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for i64x2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn bitxor(self, rhs: i64x2) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for i64x2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm_sll_epi64(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for i64x2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output {
+        let sign = self.sign_mask();
+        i64x2::from(unsafe { _mm_srl_epi64((self ^ sign).v, _mm_cvtsi32_si128(rhs)) }) ^ sign
+    }
+}
+
+impl ShrAssign<i32> for i64x2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn shl(self, rhs: i64x2) -> Self::Output { unsafe { _mm_sllv_epi64(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<i64x2> for i64x2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64x2) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i64x2> for i64x2 {
+    type Output = i64x2;
+
+    #[inline]
+    fn shr(self, rhs: i64x2) -> Self::Output {
+        let sign = self.sign_mask();
+        i64x2::from(unsafe { _mm_srlv_epi64((self ^ sign).v, rhs.v) }) ^ sign
+    }
+}
+
+impl ShrAssign<i64x2> for i64x2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64x2) { self.v = (*self >> rhs).v }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct i64x8 {
+    lo: i64x4,
+    hi: i64x4,
+}
+
+impl i64x8 {
+    #[inline]
+    pub fn new(v1: i64, v2: i64, v3: i64, v4: i64, v5: i64, v6: i64, v7: i64, v8: i64) -> Self {
+        Self::concat(i64x4::new(v1, v2, v3, v4), i64x4::new(v5, v6, v7, v8))
+    }
+
+    #[inline]
+    pub fn splat(v: i64) -> Self { Self::concat(i64x4::splat(v), i64x4::splat(v)) }
+
+    #[inline]
+    pub fn from_slice(a: &[i64]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i64) -> Self { Self::concat(i64x4::from_ptr(a), i64x4::from_ptr(a.add(4))) }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i64]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i64) -> Self {
+        Self::concat(i64x4::from_ptr_aligned(a), i64x4::from_ptr_aligned(a.add(4)))
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i64]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at(a.len().min(4));
+        Self::concat(i64x4::load_partial(lo), i64x4::load_partial(hi))
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i64]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i64) -> Self {
+        Self::concat(i64x4::from_ptr_stream(a), i64x4::from_ptr_stream(a.add(4)))
+    }
+
+    #[inline]
+    pub fn permute<const IMM: i32>(self) -> Self { Self::concat(self.lo.permute::<IMM>(), self.hi.permute::<IMM>()) }
+
+    #[inline]
+    pub unsafe fn gather_ptr<const SCALE: i32>(a: *const i64, idx: i64x8) -> Self {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::i64gather_epi64::<SCALE>(a, idx);
+        }
+
+        Self::concat(i64x4::gather_ptr::<SCALE>(a, idx.lo()), i64x4::gather_ptr::<SCALE>(a, idx.hi()))
+    }
+
+    #[inline]
+    pub unsafe fn gather_unchecked<const SCALE: i32>(a: &[i64], idx: i64x8) -> Self {
+        Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[i64], idx: i64x8) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<8>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[i64], idx: i64x8) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<8>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[i64], idx: i64x8, mask: m64x8, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<8>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const i64, idx: i64x8, mask: m64x8, default: Self) -> Self {
+        Self::concat(
+            i64x4::gather_masked_ptr::<SCALE>(a, idx.lo(), mask.lo(), default.lo),
+            i64x4::gather_masked_ptr::<SCALE>(a, idx.hi(), mask.hi(), default.hi),
+        )
+    }
+
+    /// Writes each lane to `a[idx[i]]`, in lane order so the last lane wins on duplicate indices. Panics if an index
+    /// is out of bounds.
+    #[inline]
+    pub fn scatter(self, a: &mut [i64], idx: i64x8) {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_ptr::<8>(a.as_mut_ptr(), idx) }
+    }
+
+    /// [`scatter`](Self::scatter) returning the lanes with out of bounds indices as an error, without writing any.
+    #[inline]
+    pub fn try_scatter(self, a: &mut [i64], idx: i64x8) -> Result<(), OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        unsafe { self.scatter_ptr::<8>(a.as_mut_ptr(), idx) };
+        Ok(())
+    }
+
+    /// [`scatter`](Self::scatter) of the lanes set in `mask`, panics if an index of a set lane is out of bounds.
+    #[inline]
+    pub fn scatter_masked(self, a: &mut [i64], idx: i64x8, mask: m64x8) {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { self.scatter_masked_ptr::<8>(a.as_mut_ptr(), idx, mask) }
+    }
+
+    #[inline]
+    pub unsafe fn scatter_ptr<const SCALE: i32>(self, a: *mut i64, idx: i64x8) {
+        self.scatter_masked_ptr::<SCALE>(a, idx, m64x8::splat(true))
+    }
+
+    /// Native with AVX-512F, otherwise one store per lane.
+    #[inline]
+    pub unsafe fn scatter_masked_ptr<const SCALE: i32>(self, a: *mut i64, idx: i64x8, mask: m64x8) {
+        #[cfg(all(not(feature = "scalar"), any(target_arch = "x86", target_arch = "x86_64")))]
+        if has_avx512f() {
+            return avx512::mask_i64scatter_epi64::<SCALE>(a, mask.to_bitmask(), idx, self);
+        }
+
+        self.lo.scatter_masked_ptr::<SCALE>(a, idx.lo(), mask.lo());
+        self.hi.scatter_masked_ptr::<SCALE>(a, idx.hi(), mask.hi())
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x16 { i32x16::concat(self.lo.to_raw_i32(), self.hi.to_raw_i32()) }
+
+    #[inline]
+    pub fn to_raw_f64(self) -> f64x8 { f64x8::concat(self.lo.to_raw_f64(), self.hi.to_raw_f64()) }
+
+    #[inline]
+    pub fn store(self, a: &mut [i64]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [i64]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut i64) {
+        self.lo.store_ptr(a);
+        self.hi.store_ptr(a.add(4));
+    }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i64]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i64) {
+        self.lo.store_ptr_aligned(a);
+        self.hi.store_ptr_aligned(a.add(4));
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i64]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let (lo, hi) = a.split_at_mut(a.len().min(4));
+        self.lo.store_partial(lo);
+        self.hi.store_partial(hi);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i64]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i64) {
+        self.lo.store_ptr_stream(a);
+        self.hi.store_ptr_stream(a.add(4));
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { Self::concat(self.lo.shl::<N>(), self.hi.shl::<N>()) }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self {
+        Self::concat(self.lo.shr_logical::<N>(), self.hi.shr_logical::<N>())
+    }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self { Self::concat(self.lo.shr_arith::<N>(), self.hi.shr_arith::<N>()) }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { Self::concat(self.lo.andnot(other.lo), self.hi.andnot(other.hi)) }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::concat(self.lo.min(other.lo), self.hi.min(other.hi)) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::concat(self.lo.max(other.lo), self.hi.max(other.hi)) }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i64 { (self.lo + self.hi).sum() }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i128 { self.lo.sum_widening() + self.hi.sum_widening() }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i64 { self.lo.product().wrapping_mul(self.hi.product()) }
+
+    #[inline]
+    pub fn reduce_min(self) -> i64 { self.lo.min(self.hi).reduce_min() }
+
+    #[inline]
+    pub fn reduce_max(self) -> i64 { self.lo.max(self.hi).reduce_max() }
+
+    #[inline]
+    pub fn reduce_and(self) -> i64 { (self.lo & self.hi).reduce_and() }
+
+    #[inline]
+    pub fn reduce_or(self) -> i64 { (self.lo | self.hi).reduce_or() }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i64 { (self.lo ^ self.hi).reduce_xor() }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x8 { m64x8::concat(self.lo.eq(other.lo), self.hi.eq(other.hi)) }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x8 { m64x8::concat(self.lo.ne(other.lo), self.hi.ne(other.hi)) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x8 { m64x8::concat(self.lo.lt(other.lo), self.hi.lt(other.hi)) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x8 { m64x8::concat(self.lo.le(other.lo), self.hi.le(other.hi)) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x8 { m64x8::concat(self.lo.gt(other.lo), self.hi.gt(other.hi)) }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x8 { m64x8::concat(self.lo.ge(other.lo), self.hi.ge(other.hi)) }
+
+    /// Lanes outside `0..len`, e.g. indices that would be out of bounds for a slice of length `len`.
+    #[inline]
+    pub fn out_of_bounds(self, len: usize) -> m64x8 {
+        m64x8::concat(self.lo.out_of_bounds(len), self.hi.out_of_bounds(len))
+    }
+
+    #[inline]
+    pub fn select(mask: m64x8, a: Self, b: Self) -> Self {
+        Self::concat(i64x4::select(mask.lo(), a.lo, b.lo), i64x4::select(mask.hi(), a.hi, b.hi))
+    }
+
+    #[inline]
+    pub fn lo(self) -> i64x4 { self.lo }
+
+    #[inline]
+    pub fn hi(self) -> i64x4 { self.hi }
+
+    #[inline]
+    pub fn concat(lo: i64x4, hi: i64x4) -> Self { Self { lo, hi } }
+
+    #[inline]
+    pub fn to_array(self) -> [i64; 8] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i64; 8] { unsafe { &*(self as *const Self as *const [i64; 8]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self {
+        let [lo, hi] = unsafe { swizzle512::<S, 8>([self.lo.into(), self.hi.into()]) };
+        Self::concat(i64x4::from(lo), i64x4::from(hi))
+    }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self {
+        let a = [self.lo.into(), self.hi.into()];
+        let b = [other.lo.into(), other.hi.into()];
+        let [lo, hi] = unsafe { shuffle512::<S, 8>(a, b) };
+        Self::concat(i64x4::from(lo), i64x4::from(hi))
+    }
+}
+
+impl Default for i64x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i64x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i64x8::eq(*self, *other).all() }
+}
+
+impl Eq for i64x8 {}
+
+impl Hash for i64x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i64; 8]> for i64x8 {
+    #[inline]
+    fn from(a: [i64; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i64x8> for [i64; 8] {
+    #[inline]
+    fn from(v: i64x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i64x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i64x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i64x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i64x8 {
+    type Output = i64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i64x8 {
+    type Item = i64;
+    type IntoIter = array::IntoIter<i64, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i64x8 {
+    type Item = &'a i64;
+    type IntoIter = slice::Iter<'a, i64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl Add<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn add(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo + rhs.lo, self.hi + rhs.hi) }
+}
+
+impl AddAssign for i64x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { *self = *self + rhs }
+}
+
+impl Sub<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn sub(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo - rhs.lo, self.hi - rhs.hi) }
+}
+
+impl SubAssign for i64x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { *self = *self - rhs }
+}
+
+impl BitAnd<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn bitand(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo & rhs.lo, self.hi & rhs.hi) }
+}
+
+impl BitAndAssign for i64x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn bitor(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo | rhs.lo, self.hi | rhs.hi) }
+}
+
+impl BitOrAssign for i64x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl BitXor<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn bitxor(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo ^ rhs.lo, self.hi ^ rhs.hi) }
+}
+
+impl BitXorAssign for i64x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { *self = *self ^ rhs }
+}
+
+impl Not for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { Self::concat(!self.lo, !self.hi) }
+}
+
+impl Shl<i32> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { Self::concat(self.lo << rhs, self.hi << rhs) }
+}
+
+impl ShlAssign<i32> for i64x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { *self = *self << rhs }
+}
+
+impl Shr<i32> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { Self::concat(self.lo >> rhs, self.hi >> rhs) }
+}
+
+impl ShrAssign<i32> for i64x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { *self = *self >> rhs }
+}
+
+impl Shl<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn shl(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo << rhs.lo, self.hi << rhs.hi) }
+}
+
+impl ShlAssign<i64x8> for i64x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i64x8) { *self = *self << rhs }
+}
+
+impl Shr<i64x8> for i64x8 {
+    type Output = i64x8;
+
+    #[inline]
+    fn shr(self, rhs: i64x8) -> Self::Output { Self::concat(self.lo >> rhs.lo, self.hi >> rhs.hi) }
+}
+
+impl ShrAssign<i64x8> for i64x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i64x8) { *self = *self >> rhs }
 }
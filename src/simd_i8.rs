@@ -0,0 +1,609 @@
+use crate::{
+    aligned::is_aligned, arch::*, detect::assert_supported, i16x16, lanes::fmt_lanes, m8x32, reduce::first_lane,
+    swizzle::shift_left256, swizzle::shift_right256, swizzle::shuffle256, swizzle::swizzle256, swizzle::Broadcast,
+    swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd, swizzle::InterleaveHi, swizzle::InterleaveLo,
+    swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight, swizzle::Swizzle, u8x32,
+};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not, Shl, ShlAssign,
+        Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct i8x32 {
+    v: __m256i,
+}
+
+impl i8x32 {
+    #[inline]
+    pub fn new(
+        v1: i8,
+        v2: i8,
+        v3: i8,
+        v4: i8,
+        v5: i8,
+        v6: i8,
+        v7: i8,
+        v8: i8,
+        v9: i8,
+        v10: i8,
+        v11: i8,
+        v12: i8,
+        v13: i8,
+        v14: i8,
+        v15: i8,
+        v16: i8,
+        v17: i8,
+        v18: i8,
+        v19: i8,
+        v20: i8,
+        v21: i8,
+        v22: i8,
+        v23: i8,
+        v24: i8,
+        v25: i8,
+        v26: i8,
+        v27: i8,
+        v28: i8,
+        v29: i8,
+        v30: i8,
+        v31: i8,
+        v32: i8,
+    ) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe {
+                _mm256_set_epi8(
+                    v32, v31, v30, v29, v28, v27, v26, v25, v24, v23, v22, v21, v20, v19, v18, v17, v16, v15, v14, v13,
+                    v12, v11, v10, v9, v8, v7, v6, v5, v4, v3, v2, v1,
+                )
+            },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: i8) -> i8x32 {
+        assert_supported();
+        unsafe { _mm256_set1_epi8(v) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[i8]) -> Self {
+        if a.len() < 32 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[i8]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const i8) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[i8]) -> Self {
+        if a.len() < 32 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[i8]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const i8) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 32 and zeroes the rest, or 32 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[i8]) -> Self {
+        if a.len() >= 32 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        // There are no masked loads of 8 and 16-bit lanes before AVX-512BW
+        let mut b = [0; 32];
+        b[..a.len()].copy_from_slice(a);
+        Self::from_slice(&b)
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[i8]) -> Self {
+        if a.len() < 32 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const i8) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> i8 { unsafe { _mm256_extract_epi8::<IDX>(self.v) as i8 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: i8) -> Self { unsafe { _mm256_insert_epi8::<IDX>(self.v, v) }.into() }
+
+    #[inline]
+    pub fn to_raw_i16(self) -> i16x16 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u8(self) -> u8x32 { self.v.into() }
+
+    #[inline]
+    pub fn to_i16_lo(self) -> i16x16 { unsafe { _mm256_cvtepi8_epi16(_mm256_castsi256_si128(self.v)) }.into() }
+
+    #[inline]
+    pub fn to_i16_hi(self) -> i16x16 { unsafe { _mm256_cvtepi8_epi16(_mm256_extracti128_si256::<1>(self.v)) }.into() }
+
+    #[inline]
+    pub fn shuffle(self, idx: i8x32) -> Self { unsafe { _mm256_shuffle_epi8(self.v, idx.v) }.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [i8]) {
+        if a.len() < 32 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [i8]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut i8) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [i8]) {
+        if a.len() < 32 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [i8]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut i8) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 32, or 32 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [i8]) {
+        if a.len() >= 32 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let mut b = [0; 32];
+        self.store(&mut b);
+        a.copy_from_slice(&b[..a.len()]);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [i8]) {
+        if a.len() < 32 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut i8) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self {
+        // No 8-bit shifts on x86, shift 16-bit lanes and clear the bits crossing over from the neighbouring byte
+        let mask = 0xffu8.checked_shl(N as u32).unwrap_or(0) as i8;
+        unsafe { _mm256_and_si256(_mm256_slli_epi16::<N>(self.v), _mm256_set1_epi8(mask)) }.into()
+    }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self {
+        let mask = 0xffu8.checked_shr(N as u32).unwrap_or(0) as i8;
+        unsafe { _mm256_and_si256(_mm256_srli_epi16::<N>(self.v), _mm256_set1_epi8(mask)) }.into()
+    }
+
+    #[inline]
+    pub fn shr_arith<const N: i32>(self) -> Self {
+        let sign = self.sign_mask();
+        (self ^ sign).shr_logical::<N>() ^ sign
+    }
+
+    #[inline]
+    fn sign_mask(self) -> Self { unsafe { _mm256_cmpgt_epi8(_mm256_setzero_si256(), self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm256_min_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm256_max_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> i8 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            let v = op(v, _mm_srli_si128::<8>(v));
+            let v = op(v, _mm_srli_si128::<4>(v));
+            let v = op(v, _mm_srli_si128::<2>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<1>(v))) as i8
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> i8 { self.reduce(|a, b| unsafe { _mm_add_epi8(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> i32 { (self.to_i16_lo() + self.to_i16_hi()).sum() as i32 }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> i8 { (self.to_i16_lo() * self.to_i16_hi()).product() as i8 }
+
+    #[inline]
+    pub fn reduce_min(self) -> i8 { self.reduce(|a, b| unsafe { _mm_min_epi8(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> i8 { self.reduce(|a, b| unsafe { _mm_max_epi8(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> i8 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> i8 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> i8 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask()) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask()) }
+
+    #[inline]
+    pub fn adds(self, other: Self) -> Self { unsafe { _mm256_adds_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn subs(self, other: Self) -> Self { unsafe { _mm256_subs_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn abs(self) -> Self { unsafe { _mm256_abs_epi8(self.v) }.into() }
+
+    #[inline]
+    pub fn sign(self, other: Self) -> Self { unsafe { _mm256_sign_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m8x32 { unsafe { _mm256_cmpeq_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m8x32 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m8x32 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m8x32 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m8x32 { unsafe { _mm256_cmpgt_epi8(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m8x32 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m8x32, a: Self, b: Self) -> Self { unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> i8 { unsafe { _mm256_extract_epi8::<INDEX>(self.v) as i8 } }
+
+    #[inline]
+    pub fn to_array(self) -> [i8; 32] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[i8; 32] { unsafe { &*(self as *const Self as *const [i8; 32]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> i8 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: i8) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, i8> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> i8>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 32>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 32>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<32>>() }
+
+    /// Lane `(i + N) % 32` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<32, N>>() }
+
+    /// Lane `(i - N) % 32` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<32, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 32.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<32, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 32.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<32, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<32, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<32>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<32>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<32>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<32>>(other) }
+}
+
+impl Default for i8x32 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for i8x32 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { i8x32::eq(*self, *other).all() }
+}
+
+impl Eq for i8x32 {}
+
+impl Hash for i8x32 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[i8; 32]> for i8x32 {
+    #[inline]
+    fn from(a: [i8; 32]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<i8x32> for [i8; 32] {
+    #[inline]
+    fn from(v: i8x32) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for i8x32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("i8x32").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for i8x32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for i8x32 {
+    type Output = i8;
+
+    #[inline]
+    fn index(&self, i: usize) -> &i8 { &self.as_array()[i] }
+}
+
+impl IntoIterator for i8x32 {
+    type Item = i8;
+    type IntoIter = array::IntoIter<i8, 32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a i8x32 {
+    type Item = &'a i8;
+    type IntoIter = slice::Iter<'a, i8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256i> for i8x32 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<i8x32> for __m256i {
+    #[inline]
+    fn from(v: i8x32) -> Self { v.v }
+}
+
+impl Add<i8x32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn add(self, rhs: i8x32) -> Self::Output { unsafe { _mm256_add_epi8(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for i8x32 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<i8x32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn sub(self, rhs: i8x32) -> Self::Output { unsafe { _mm256_sub_epi8(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for i8x32 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<i8x32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn bitand(self, rhs: i8x32) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for i8x32 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<i8x32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn bitor(self, rhs: i8x32) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for i8x32 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<i8x32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn bitxor(self, rhs: i8x32) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for i8x32 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output {
+        let mask = 0xffu8.checked_shl(rhs as u32).unwrap_or(0) as i8;
+        unsafe { _mm256_and_si256(_mm256_sll_epi16(self.v, _mm_cvtsi32_si128(rhs)), _mm256_set1_epi8(mask)) }.into()
+    }
+}
+
+impl ShlAssign<i32> for i8x32 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for i8x32 {
+    type Output = i8x32;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output {
+        let sign = self.sign_mask();
+        let mask = 0xffu8.checked_shr(rhs as u32).unwrap_or(0) as i8;
+        let shifted = unsafe {
+            _mm256_and_si256(_mm256_srl_epi16((self ^ sign).v, _mm_cvtsi32_si128(rhs)), _mm256_set1_epi8(mask))
+        };
+        i8x32::from(shifted) ^ sign
+    }
+}
+
+impl ShrAssign<i32> for i8x32 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
@@ -0,0 +1,982 @@
+use crate::{arch::*, detect::assert_supported};
+use std::{
+    fmt,
+    hash::{Hash, Hasher},
+    ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not},
+};
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m8x32 {
+    v: __m256i,
+}
+
+impl m8x32 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_epi8(-(v as i8)) }.into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm256_testz_si256(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm256_movemask_epi8(self.v) == -1 } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u32 { unsafe { _mm256_movemask_epi8(self.v) as u32 } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 32] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m8x32 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m8x32 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m8x32 {}
+
+impl Hash for m8x32 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m8x32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m8x32").field(&self.to_array()).finish() }
+}
+
+impl From<__m256i> for m8x32 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<m8x32> for __m256i {
+    #[inline]
+    fn from(v: m8x32) -> Self { v.v }
+}
+
+impl BitAnd<m8x32> for m8x32 {
+    type Output = m8x32;
+
+    #[inline]
+    fn bitand(self, rhs: m8x32) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m8x32 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m8x32> for m8x32 {
+    type Output = m8x32;
+
+    #[inline]
+    fn bitor(self, rhs: m8x32) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m8x32 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m8x32> for m8x32 {
+    type Output = m8x32;
+
+    #[inline]
+    fn bitxor(self, rhs: m8x32) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m8x32 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m8x32 {
+    type Output = m8x32;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m16x16 {
+    v: __m256i,
+}
+
+impl m16x16 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_epi16(-(v as i16)) }.into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm256_testz_si256(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm256_movemask_epi8(self.v) == -1 } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u16 {
+        unsafe {
+            let m = _mm256_movemask_epi8(_mm256_packs_epi16(self.v, self.v)) as u32;
+            (m & 0xff | (m >> 8) & 0xff00) as u16
+        }
+    }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 16] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m16x16 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m16x16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m16x16 {}
+
+impl Hash for m16x16 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m16x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("m16x16").field(&self.to_array()).finish()
+    }
+}
+
+impl From<__m256i> for m16x16 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<m16x16> for __m256i {
+    #[inline]
+    fn from(v: m16x16) -> Self { v.v }
+}
+
+impl BitAnd<m16x16> for m16x16 {
+    type Output = m16x16;
+
+    #[inline]
+    fn bitand(self, rhs: m16x16) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m16x16 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m16x16> for m16x16 {
+    type Output = m16x16;
+
+    #[inline]
+    fn bitor(self, rhs: m16x16) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m16x16 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m16x16> for m16x16 {
+    type Output = m16x16;
+
+    #[inline]
+    fn bitxor(self, rhs: m16x16) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m16x16 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m16x16 {
+    type Output = m16x16;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m32x8 {
+    v: __m256i,
+}
+
+impl m32x8 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_epi32(-(v as i32)) }.into()
+    }
+
+    #[inline]
+    pub fn from_bitmask(bits: u8) -> Self {
+        assert_supported();
+        unsafe {
+            let lanes = _mm256_set_epi32(128, 64, 32, 16, 8, 4, 2, 1);
+            _mm256_cmpeq_epi32(_mm256_and_si256(_mm256_set1_epi32(bits as i32), lanes), lanes)
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm256_testz_si256(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm256_movemask_epi8(self.v) == -1 } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u8 { unsafe { _mm256_movemask_ps(_mm256_castsi256_ps(self.v)) as u8 } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 8] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m32x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m32x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m32x8 {}
+
+impl Hash for m32x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m32x8").field(&self.to_array()).finish() }
+}
+
+impl From<__m256i> for m32x8 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<m32x8> for __m256i {
+    #[inline]
+    fn from(v: m32x8) -> Self { v.v }
+}
+
+impl BitAnd<m32x8> for m32x8 {
+    type Output = m32x8;
+
+    #[inline]
+    fn bitand(self, rhs: m32x8) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m32x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m32x8> for m32x8 {
+    type Output = m32x8;
+
+    #[inline]
+    fn bitor(self, rhs: m32x8) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m32x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m32x8> for m32x8 {
+    type Output = m32x8;
+
+    #[inline]
+    fn bitxor(self, rhs: m32x8) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m32x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m32x8 {
+    type Output = m32x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m64x4 {
+    v: __m256i,
+}
+
+impl m64x4 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm256_set1_epi64x(-(v as i64)) }.into()
+    }
+
+    #[inline]
+    pub fn from_bitmask(bits: u8) -> Self {
+        assert_supported();
+        unsafe {
+            let lanes = _mm256_set_epi64x(8, 4, 2, 1);
+            _mm256_cmpeq_epi64(_mm256_and_si256(_mm256_set1_epi64x(bits as i64), lanes), lanes)
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm256_testz_si256(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm256_movemask_epi8(self.v) == -1 } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u8 { unsafe { _mm256_movemask_pd(_mm256_castsi256_pd(self.v)) as u8 } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 4] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m64x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m64x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m64x4 {}
+
+impl Hash for m64x4 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m64x4").field(&self.to_array()).finish() }
+}
+
+impl From<__m256i> for m64x4 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<m64x4> for __m256i {
+    #[inline]
+    fn from(v: m64x4) -> Self { v.v }
+}
+
+impl BitAnd<m64x4> for m64x4 {
+    type Output = m64x4;
+
+    #[inline]
+    fn bitand(self, rhs: m64x4) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m64x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m64x4> for m64x4 {
+    type Output = m64x4;
+
+    #[inline]
+    fn bitor(self, rhs: m64x4) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m64x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m64x4> for m64x4 {
+    type Output = m64x4;
+
+    #[inline]
+    fn bitxor(self, rhs: m64x4) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m64x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m64x4 {
+    type Output = m64x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m16x8 {
+    v: __m128i,
+}
+
+impl m16x8 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm_set1_epi16(-(v as i16)) }.into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm_testz_si128(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm_movemask_epi8(self.v) == 0xffff } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u8 { unsafe { _mm_movemask_epi8(_mm_packs_epi16(self.v, self.v)) as u8 } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 8] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m16x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m16x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m16x8 {}
+
+impl Hash for m16x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m16x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m16x8").field(&self.to_array()).finish() }
+}
+
+impl From<__m128i> for m16x8 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<m16x8> for __m128i {
+    #[inline]
+    fn from(v: m16x8) -> Self { v.v }
+}
+
+impl BitAnd<m16x8> for m16x8 {
+    type Output = m16x8;
+
+    #[inline]
+    fn bitand(self, rhs: m16x8) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m16x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m16x8> for m16x8 {
+    type Output = m16x8;
+
+    #[inline]
+    fn bitor(self, rhs: m16x8) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m16x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m16x8> for m16x8 {
+    type Output = m16x8;
+
+    #[inline]
+    fn bitxor(self, rhs: m16x8) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m16x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m16x8 {
+    type Output = m16x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m32x4 {
+    v: __m128i,
+}
+
+impl m32x4 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm_set1_epi32(-(v as i32)) }.into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm_testz_si128(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm_movemask_epi8(self.v) == 0xffff } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u8 { unsafe { _mm_movemask_ps(_mm_castsi128_ps(self.v)) as u8 } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 4] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m32x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m32x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m32x4 {}
+
+impl Hash for m32x4 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m32x4").field(&self.to_array()).finish() }
+}
+
+impl From<__m128i> for m32x4 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<m32x4> for __m128i {
+    #[inline]
+    fn from(v: m32x4) -> Self { v.v }
+}
+
+impl BitAnd<m32x4> for m32x4 {
+    type Output = m32x4;
+
+    #[inline]
+    fn bitand(self, rhs: m32x4) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m32x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m32x4> for m32x4 {
+    type Output = m32x4;
+
+    #[inline]
+    fn bitor(self, rhs: m32x4) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m32x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m32x4> for m32x4 {
+    type Output = m32x4;
+
+    #[inline]
+    fn bitxor(self, rhs: m32x4) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m32x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m32x4 {
+    type Output = m32x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m64x2 {
+    v: __m128i,
+}
+
+impl m64x2 {
+    #[inline]
+    pub fn splat(v: bool) -> Self {
+        assert_supported();
+        unsafe { _mm_set1_epi64x(-(v as i64)) }.into()
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { unsafe { _mm_testz_si128(self.v, self.v) == 0 } }
+
+    #[inline]
+    pub fn all(self) -> bool { unsafe { _mm_movemask_epi8(self.v) == 0xffff } }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u8 { unsafe { _mm_movemask_pd(_mm_castsi128_pd(self.v)) as u8 } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 2] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m64x2 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m64x2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m64x2 {}
+
+impl Hash for m64x2 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m64x2").field(&self.to_array()).finish() }
+}
+
+impl From<__m128i> for m64x2 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<m64x2> for __m128i {
+    #[inline]
+    fn from(v: m64x2) -> Self { v.v }
+}
+
+impl BitAnd<m64x2> for m64x2 {
+    type Output = m64x2;
+
+    #[inline]
+    fn bitand(self, rhs: m64x2) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for m64x2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<m64x2> for m64x2 {
+    type Output = m64x2;
+
+    #[inline]
+    fn bitor(self, rhs: m64x2) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for m64x2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<m64x2> for m64x2 {
+    type Output = m64x2;
+
+    #[inline]
+    fn bitxor(self, rhs: m64x2) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for m64x2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for m64x2 {
+    type Output = m64x2;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m32x16 {
+    lo: m32x8,
+    hi: m32x8,
+}
+
+impl m32x16 {
+    #[inline]
+    pub fn splat(v: bool) -> Self { Self::concat(m32x8::splat(v), m32x8::splat(v)) }
+
+    #[inline]
+    pub fn from_bitmask(bits: u16) -> Self {
+        Self::concat(m32x8::from_bitmask(bits as u8), m32x8::from_bitmask((bits >> 8) as u8))
+    }
+
+    #[inline]
+    pub fn any(self) -> bool { (self.lo | self.hi).any() }
+
+    #[inline]
+    pub fn all(self) -> bool { (self.lo & self.hi).all() }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u16 { self.lo.to_bitmask() as u16 | (self.hi.to_bitmask() as u16) << 8 }
+
+    #[inline]
+    pub fn lo(self) -> m32x8 { self.lo }
+
+    #[inline]
+    pub fn hi(self) -> m32x8 { self.hi }
+
+    #[inline]
+    pub fn concat(lo: m32x8, hi: m32x8) -> Self { Self { lo, hi } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 16] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m32x16 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m32x16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m32x16 {}
+
+impl Hash for m32x16 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m32x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("m32x16").field(&self.to_array()).finish()
+    }
+}
+
+impl BitAnd<m32x16> for m32x16 {
+    type Output = m32x16;
+
+    #[inline]
+    fn bitand(self, rhs: m32x16) -> Self::Output { Self::concat(self.lo & rhs.lo, self.hi & rhs.hi) }
+}
+
+impl BitAndAssign for m32x16 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr<m32x16> for m32x16 {
+    type Output = m32x16;
+
+    #[inline]
+    fn bitor(self, rhs: m32x16) -> Self::Output { Self::concat(self.lo | rhs.lo, self.hi | rhs.hi) }
+}
+
+impl BitOrAssign for m32x16 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl BitXor<m32x16> for m32x16 {
+    type Output = m32x16;
+
+    #[inline]
+    fn bitxor(self, rhs: m32x16) -> Self::Output { Self::concat(self.lo ^ rhs.lo, self.hi ^ rhs.hi) }
+}
+
+impl BitXorAssign for m32x16 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { *self = *self ^ rhs }
+}
+
+impl Not for m32x16 {
+    type Output = m32x16;
+
+    #[inline]
+    fn not(self) -> Self::Output { Self::concat(!self.lo, !self.hi) }
+}
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+pub struct m64x8 {
+    lo: m64x4,
+    hi: m64x4,
+}
+
+impl m64x8 {
+    #[inline]
+    pub fn splat(v: bool) -> Self { Self::concat(m64x4::splat(v), m64x4::splat(v)) }
+
+    #[inline]
+    pub fn from_bitmask(bits: u8) -> Self { Self::concat(m64x4::from_bitmask(bits), m64x4::from_bitmask(bits >> 4)) }
+
+    #[inline]
+    pub fn any(self) -> bool { (self.lo | self.hi).any() }
+
+    #[inline]
+    pub fn all(self) -> bool { (self.lo & self.hi).all() }
+
+    #[inline]
+    pub fn none(self) -> bool { !self.any() }
+
+    #[inline]
+    pub fn to_bitmask(self) -> u8 { self.lo.to_bitmask() | self.hi.to_bitmask() << 4 }
+
+    #[inline]
+    pub fn lo(self) -> m64x4 { self.lo }
+
+    #[inline]
+    pub fn hi(self) -> m64x4 { self.hi }
+
+    #[inline]
+    pub fn concat(lo: m64x4, hi: m64x4) -> Self { Self { lo, hi } }
+
+    #[inline]
+    pub fn to_array(self) -> [bool; 8] {
+        let bits = self.to_bitmask();
+        std::array::from_fn(|i| bits >> i & 1 != 0)
+    }
+}
+
+impl Default for m64x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(false) }
+}
+
+impl PartialEq for m64x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.to_bitmask() == other.to_bitmask() }
+}
+
+impl Eq for m64x8 {}
+
+impl Hash for m64x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.to_bitmask().hash(state) }
+}
+
+impl fmt::Debug for m64x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("m64x8").field(&self.to_array()).finish() }
+}
+
+impl BitAnd<m64x8> for m64x8 {
+    type Output = m64x8;
+
+    #[inline]
+    fn bitand(self, rhs: m64x8) -> Self::Output { Self::concat(self.lo & rhs.lo, self.hi & rhs.hi) }
+}
+
+impl BitAndAssign for m64x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { *self = *self & rhs }
+}
+
+impl BitOr<m64x8> for m64x8 {
+    type Output = m64x8;
+
+    #[inline]
+    fn bitor(self, rhs: m64x8) -> Self::Output { Self::concat(self.lo | rhs.lo, self.hi | rhs.hi) }
+}
+
+impl BitOrAssign for m64x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { *self = *self | rhs }
+}
+
+impl BitXor<m64x8> for m64x8 {
+    type Output = m64x8;
+
+    #[inline]
+    fn bitxor(self, rhs: m64x8) -> Self::Output { Self::concat(self.lo ^ rhs.lo, self.hi ^ rhs.hi) }
+}
+
+impl BitXorAssign for m64x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { *self = *self ^ rhs }
+}
+
+impl Not for m64x8 {
+    type Output = m64x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { Self::concat(!self.lo, !self.hi) }
+}
@@ -0,0 +1,1127 @@
+use crate::{
+    aligned::is_aligned, arch::*, detect::assert_supported, i16x16, i16x8, lanes::fmt_lanes, m16x16, m16x8,
+    reduce::first_lane, swizzle::shift_left128, swizzle::shift_left256, swizzle::shift_right128,
+    swizzle::shift_right256, swizzle::shuffle128, swizzle::shuffle256, swizzle::swizzle128, swizzle::swizzle256,
+    swizzle::Broadcast, swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd, swizzle::InterleaveHi,
+    swizzle::InterleaveLo, swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight, swizzle::Swizzle, u32x4, u32x8,
+    u64x2, u64x4,
+};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Mul, MulAssign, Not,
+        Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct u16x16 {
+    v: __m256i,
+}
+
+impl u16x16 {
+    #[inline]
+    pub fn new(
+        v1: u16,
+        v2: u16,
+        v3: u16,
+        v4: u16,
+        v5: u16,
+        v6: u16,
+        v7: u16,
+        v8: u16,
+        v9: u16,
+        v10: u16,
+        v11: u16,
+        v12: u16,
+        v13: u16,
+        v14: u16,
+        v15: u16,
+        v16: u16,
+    ) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe {
+                _mm256_set_epi16(
+                    v16 as i16, v15 as i16, v14 as i16, v13 as i16, v12 as i16, v11 as i16, v10 as i16, v9 as i16,
+                    v8 as i16, v7 as i16, v6 as i16, v5 as i16, v4 as i16, v3 as i16, v2 as i16, v1 as i16,
+                )
+            },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: u16) -> u16x16 {
+        assert_supported();
+        unsafe { _mm256_set1_epi16(v as i16) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[u16]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[u16]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const u16) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[u16]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[u16]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const u16) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 16 and zeroes the rest, or 16 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[u16]) -> Self {
+        if a.len() >= 16 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        // There are no masked loads of 8 and 16-bit lanes before AVX-512BW
+        let mut b = [0; 16];
+        b[..a.len()].copy_from_slice(a);
+        Self::from_slice(&b)
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[u16]) -> Self {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const u16) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        unsafe { _mm256_blend_epi16::<IMM>(self.v, other.v) }.into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> u16 { unsafe { _mm256_extract_epi16::<IDX>(self.v) as u16 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: u16) -> Self { unsafe { _mm256_insert_epi16::<IDX>(self.v, v as i16) }.into() }
+
+    #[inline]
+    pub fn to_raw_i16(self) -> i16x16 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u32(self) -> u32x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u64(self) -> u64x4 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [u16]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [u16]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut u16) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [u16]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [u16]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut u16) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 16, or 16 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [u16]) {
+        if a.len() >= 16 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let mut b = [0; 16];
+        self.store(&mut b);
+        a.copy_from_slice(&b[..a.len()]);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [u16]) {
+        if a.len() < 16 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut u16) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm256_slli_epi16::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm256_srli_epi16::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm256_min_epu16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm256_max_epu16(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> u16 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            let v = op(v, _mm_srli_si128::<8>(v));
+            let v = op(v, _mm_srli_si128::<4>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<2>(v))) as u16
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> u16 { self.reduce(|a, b| unsafe { _mm_add_epi16(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> u32 {
+        u32x8::from(unsafe { _mm256_cvtepu16_epi32(self.lo().into()) }).sum() +
+            u32x8::from(unsafe { _mm256_cvtepu16_epi32(self.hi().into()) }).sum()
+    }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> u16 { self.reduce(|a, b| unsafe { _mm_mullo_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> u16 { self.reduce(|a, b| unsafe { _mm_min_epu16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> u16 { self.reduce(|a, b| unsafe { _mm_max_epu16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> u16 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> u16 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> u16 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m16x16 { unsafe { _mm256_cmpeq_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m16x16 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m16x16 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m16x16 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m16x16 {
+        unsafe {
+            let sign = _mm256_set1_epi16(i16::MIN);
+            _mm256_cmpgt_epi16(_mm256_xor_si256(self.v, sign), _mm256_xor_si256(other.v, sign))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m16x16 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m16x16, a: Self, b: Self) -> Self {
+        unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into()
+    }
+
+    #[inline]
+    pub fn lo(self) -> u16x8 { unsafe { _mm256_castsi256_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> u16x8 { unsafe { _mm256_extracti128_si256::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: u16x8, hi: u16x8) -> Self { unsafe { _mm256_set_m128i(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> u16 { unsafe { _mm256_extract_epi16::<INDEX>(self.v) as u16 } }
+
+    #[inline]
+    pub fn to_array(self) -> [u16; 16] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[u16; 16] { unsafe { &*(self as *const Self as *const [u16; 16]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> u16 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: u16) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, u16> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> u16>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 16>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 16>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<16>>() }
+
+    /// Lane `(i + N) % 16` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<16, N>>() }
+
+    /// Lane `(i - N) % 16` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<16, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 16.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<16, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 16.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<16, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<16, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<16>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<16>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<16>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<16>>(other) }
+}
+
+impl Default for u16x16 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for u16x16 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { u16x16::eq(*self, *other).all() }
+}
+
+impl Eq for u16x16 {}
+
+impl Hash for u16x16 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[u16; 16]> for u16x16 {
+    #[inline]
+    fn from(a: [u16; 16]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<u16x16> for [u16; 16] {
+    #[inline]
+    fn from(v: u16x16) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for u16x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("u16x16").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for u16x16 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for u16x16 {
+    type Output = u16;
+
+    #[inline]
+    fn index(&self, i: usize) -> &u16 { &self.as_array()[i] }
+}
+
+impl IntoIterator for u16x16 {
+    type Item = u16;
+    type IntoIter = array::IntoIter<u16, 16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a u16x16 {
+    type Item = &'a u16;
+    type IntoIter = slice::Iter<'a, u16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256i> for u16x16 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<u16x16> for __m256i {
+    #[inline]
+    fn from(v: u16x16) -> Self { v.v }
+}
+
+impl Mul<u16x16> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn mul(self, rhs: u16x16) -> Self::Output { unsafe { _mm256_mullo_epi16(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<u16x16> for u16x16 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u16x16) { self.v = (*self * rhs).v }
+}
+
+impl Add<u16x16> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn add(self, rhs: u16x16) -> Self::Output { unsafe { _mm256_add_epi16(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for u16x16 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<u16x16> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn sub(self, rhs: u16x16) -> Self::Output { unsafe { _mm256_sub_epi16(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for u16x16 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<u16x16> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn bitand(self, rhs: u16x16) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for u16x16 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<u16x16> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn bitor(self, rhs: u16x16) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for u16x16 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<u16x16> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn bitxor(self, rhs: u16x16) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for u16x16 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm256_sll_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for u16x16 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for u16x16 {
+    type Output = u16x16;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm256_srl_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for u16x16 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+/// The 128-bit counterpart of [`u16x16`]. `lo`/`hi`/`concat` are 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct u16x8 {
+    v: __m128i,
+}
+
+impl u16x8 {
+    #[inline]
+    pub fn new(v1: u16, v2: u16, v3: u16, v4: u16, v5: u16, v6: u16, v7: u16, v8: u16) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe {
+                _mm_set_epi16(v8 as i16, v7 as i16, v6 as i16, v5 as i16, v4 as i16, v3 as i16, v2 as i16, v1 as i16)
+            },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: u16) -> u16x8 {
+        assert_supported();
+        unsafe { _mm_set1_epi16(v as i16) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[u16]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[u16]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const u16) -> Self {
+        assert_supported();
+        _mm_loadu_si128(a as *const __m128i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[u16]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[u16]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const u16) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_si128(a as *const __m128i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[u16]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        // There are no masked loads of 8 and 16-bit lanes before AVX-512BW
+        let mut b = [0; 8];
+        b[..a.len()].copy_from_slice(a);
+        Self::from_slice(&b)
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[u16]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const u16) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_stream_load_si128(a as *const __m128i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> u16 { unsafe { _mm_extract_epi16::<IDX>(self.v) as u16 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: u16) -> Self { unsafe { _mm_insert_epi16::<IDX>(self.v, v as i32) }.into() }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        unsafe { _mm_blend_epi16::<IMM>(self.v, other.v) }.into()
+    }
+
+    #[inline]
+    pub fn to_raw_i16(self) -> i16x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u32(self) -> u32x4 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u64(self) -> u64x2 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [u16]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [u16]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut u16) { _mm_storeu_si128(a as *mut __m128i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [u16]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [u16]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut u16) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_si128(a as *mut __m128i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [u16]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        let mut b = [0; 8];
+        self.store(&mut b);
+        a.copy_from_slice(&b[..a.len()]);
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [u16]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut u16) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_si128(a as *mut __m128i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm_slli_epi16::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm_srli_epi16::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_si128(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm_min_epu16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm_max_epu16(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> u16 {
+        unsafe {
+            let v = self.v;
+            let v = op(v, _mm_srli_si128::<8>(v));
+            let v = op(v, _mm_srli_si128::<4>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<2>(v))) as u16
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> u16 { self.reduce(|a, b| unsafe { _mm_add_epi16(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> u32 {
+        u32x4::from(unsafe { _mm_cvtepu16_epi32(self.v) }).sum() +
+            u32x4::from(unsafe { _mm_cvtepu16_epi32(_mm_srli_si128::<8>(self.v)) }).sum()
+    }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> u16 { self.reduce(|a, b| unsafe { _mm_mullo_epi16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> u16 { self.reduce(|a, b| unsafe { _mm_min_epu16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> u16 { self.reduce(|a, b| unsafe { _mm_max_epu16(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> u16 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> u16 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> u16 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m16x8 { unsafe { _mm_cmpeq_epi16(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m16x8 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m16x8 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m16x8 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m16x8 {
+        unsafe {
+            let sign = _mm_set1_epi16(i16::MIN);
+            _mm_cmpgt_epi16(_mm_xor_si128(self.v, sign), _mm_xor_si128(other.v, sign))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m16x8 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m16x8, a: Self, b: Self) -> Self { unsafe { _mm_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> u16 { unsafe { _mm_extract_epi16::<INDEX>(self.v) as u16 } }
+
+    #[inline]
+    pub fn to_array(self) -> [u16; 8] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[u16; 8] { unsafe { &*(self as *const Self as *const [u16; 8]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> u16 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: u16) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, u16> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> u16>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle128::<S, 8>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle128::<S, 8>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<8>>() }
+
+    /// Lane `(i + N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<8, N>>() }
+
+    /// Lane `(i - N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<8, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left128::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right128::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<8, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<8>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<8>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<8>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<8>>(other) }
+}
+
+impl Default for u16x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for u16x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { u16x8::eq(*self, *other).all() }
+}
+
+impl Eq for u16x8 {}
+
+impl Hash for u16x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[u16; 8]> for u16x8 {
+    #[inline]
+    fn from(a: [u16; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<u16x8> for [u16; 8] {
+    #[inline]
+    fn from(v: u16x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for u16x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("u16x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for u16x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for u16x8 {
+    type Output = u16;
+
+    #[inline]
+    fn index(&self, i: usize) -> &u16 { &self.as_array()[i] }
+}
+
+impl IntoIterator for u16x8 {
+    type Item = u16;
+    type IntoIter = array::IntoIter<u16, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a u16x8 {
+    type Item = &'a u16;
+    type IntoIter = slice::Iter<'a, u16>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128i> for u16x8 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<u16x8> for __m128i {
+    #[inline]
+    fn from(v: u16x8) -> Self { v.v }
+}
+
+impl Mul<u16x8> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn mul(self, rhs: u16x8) -> Self::Output { unsafe { _mm_mullo_epi16(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<u16x8> for u16x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u16x8) { self.v = (*self * rhs).v }
+}
+
+impl Add<u16x8> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn add(self, rhs: u16x8) -> Self::Output { unsafe { _mm_add_epi16(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for u16x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<u16x8> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn sub(self, rhs: u16x8) -> Self::Output { unsafe { _mm_sub_epi16(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for u16x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<u16x8> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn bitand(self, rhs: u16x8) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for u16x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<u16x8> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn bitor(self, rhs: u16x8) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for u16x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<u16x8> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn bitxor(self, rhs: u16x8) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for u16x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm_sll_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for u16x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for u16x8 {
+    type Output = u16x8;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm_srl_epi16(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for u16x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
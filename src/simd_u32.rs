@@ -0,0 +1,1198 @@
+use crate::{
+    aligned::is_aligned, arch::*, bounds::OutOfBounds, detect::assert_supported, f32x4, f32x8, i32x4, i32x8,
+    lanes::fmt_lanes, m32x4, m32x8, partial::mask_epi32x4, partial::mask_epi32x8, reduce::first_lane,
+    swizzle::shift_left128, swizzle::shift_left256, swizzle::shift_right128, swizzle::shift_right256,
+    swizzle::shuffle128, swizzle::shuffle256, swizzle::swizzle128, swizzle::swizzle256, swizzle::Broadcast,
+    swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd, swizzle::InterleaveHi, swizzle::InterleaveLo,
+    swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight, swizzle::Swizzle, u16x16, u16x8, u64x2, u64x4,
+};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Mul, MulAssign, Not,
+        Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct u32x8 {
+    v: __m256i,
+}
+
+impl u32x8 {
+    #[inline]
+    pub fn new(v1: u32, v2: u32, v3: u32, v4: u32, v5: u32, v6: u32, v7: u32, v8: u32) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe {
+                _mm256_set_epi32(v8 as i32, v7 as i32, v6 as i32, v5 as i32, v4 as i32, v3 as i32, v2 as i32, v1 as i32)
+            },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: u32) -> u32x8 {
+        assert_supported();
+        unsafe { _mm256_set1_epi32(v as i32) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[u32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[u32]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const u32) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[u32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[u32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const u32) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 8 and zeroes the rest, or 8 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[u32]) -> Self {
+        if a.len() >= 8 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm256_maskload_epi32(a.as_ptr() as *const i32, mask_epi32x8(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[u32]) -> Self {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const u32) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_ptr<const SCALE: i32>(a: *const u32, idx: i32x8) -> Self {
+        _mm256_i32gather_epi32::<SCALE>(a as *const i32, idx.into()).into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_unchecked<const SCALE: i32>(a: &[u32], idx: i32x8) -> Self {
+        Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[u32], idx: i32x8) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<4>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[u32], idx: i32x8) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<4>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[u32], idx: i32x8, mask: m32x8, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<4>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const u32, idx: i32x8, mask: m32x8, default: Self) -> Self {
+        _mm256_mask_i32gather_epi32::<SCALE>(default.v, a as *const i32, idx.into(), mask.into()).into()
+    }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        unsafe { _mm256_blend_epi32::<IMM>(self.v, other.v) }.into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> u32 { unsafe { _mm256_extract_epi32::<IDX>(self.v) as u32 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: u32) -> Self { unsafe { _mm256_insert_epi32::<IDX>(self.v, v as i32) }.into() }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x8 { unsafe { _mm256_castsi256_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u16(self) -> u16x16 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u64(self) -> u64x4 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [u32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [u32]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut u32) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [u32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [u32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut u32) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 8, or 8 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [u32]) {
+        if a.len() >= 8 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm256_maskstore_epi32(a.as_mut_ptr() as *mut i32, mask_epi32x8(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [u32]) {
+        if a.len() < 8 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut u32) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm256_slli_epi32::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm256_srli_epi32::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm256_min_epu32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm256_max_epu32(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> u32 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            let v = op(v, _mm_srli_si128::<8>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<4>(v))) as u32
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> u32 { self.reduce(|a, b| unsafe { _mm_add_epi32(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> u64 {
+        u64x4::from(unsafe { _mm256_cvtepu32_epi64(self.lo().into()) }).sum() +
+            u64x4::from(unsafe { _mm256_cvtepu32_epi64(self.hi().into()) }).sum()
+    }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> u32 { self.reduce(|a, b| unsafe { _mm_mullo_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> u32 { self.reduce(|a, b| unsafe { _mm_min_epu32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> u32 { self.reduce(|a, b| unsafe { _mm_max_epu32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> u32 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> u32 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> u32 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x8 { unsafe { _mm256_cmpeq_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x8 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x8 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x8 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x8 {
+        unsafe {
+            let sign = _mm256_set1_epi32(i32::MIN);
+            _mm256_cmpgt_epi32(_mm256_xor_si256(self.v, sign), _mm256_xor_si256(other.v, sign))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x8 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m32x8, a: Self, b: Self) -> Self { unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn lo(self) -> u32x4 { unsafe { _mm256_castsi256_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> u32x4 { unsafe { _mm256_extracti128_si256::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: u32x4, hi: u32x4) -> Self { unsafe { _mm256_set_m128i(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> u32 { unsafe { _mm256_extract_epi32::<INDEX>(self.v) as u32 } }
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 8] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[u32; 8] { unsafe { &*(self as *const Self as *const [u32; 8]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> u32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: u32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, u32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> u32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 8>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 8>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<8>>() }
+
+    /// Lane `(i + N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<8, N>>() }
+
+    /// Lane `(i - N) % 8` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<8, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 8.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<8, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<8, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<8>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<8>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<8>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<8>>(other) }
+}
+
+impl Default for u32x8 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for u32x8 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { u32x8::eq(*self, *other).all() }
+}
+
+impl Eq for u32x8 {}
+
+impl Hash for u32x8 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[u32; 8]> for u32x8 {
+    #[inline]
+    fn from(a: [u32; 8]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<u32x8> for [u32; 8] {
+    #[inline]
+    fn from(v: u32x8) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for u32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("u32x8").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for u32x8 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for u32x8 {
+    type Output = u32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &u32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for u32x8 {
+    type Item = u32;
+    type IntoIter = array::IntoIter<u32, 8>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a u32x8 {
+    type Item = &'a u32;
+    type IntoIter = slice::Iter<'a, u32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256i> for u32x8 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<u32x8> for __m256i {
+    #[inline]
+    fn from(v: u32x8) -> Self { v.v }
+}
+
+impl Mul<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn mul(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_mullo_epi32(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<u32x8> for u32x8 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32x8) { self.v = (*self * rhs).v }
+}
+
+impl Add<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn add(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_add_epi32(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for u32x8 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn sub(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_sub_epi32(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for u32x8 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn bitand(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for u32x8 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn bitor(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for u32x8 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn bitxor(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for u32x8 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm256_sll_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for u32x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm256_srl_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for u32x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn shl(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_sllv_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<u32x8> for u32x8 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32x8) { self.v = (*self << rhs).v }
+}
+
+impl Shr<u32x8> for u32x8 {
+    type Output = u32x8;
+
+    #[inline]
+    fn shr(self, rhs: u32x8) -> Self::Output { unsafe { _mm256_srlv_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShrAssign<u32x8> for u32x8 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32x8) { self.v = (*self >> rhs).v }
+}
+
+/// The 128-bit counterpart of [`u32x8`]. Gathers and `lo`/`hi`/`concat` are 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct u32x4 {
+    v: __m128i,
+}
+
+impl u32x4 {
+    #[inline]
+    pub fn new(v1: u32, v2: u32, v3: u32, v4: u32) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm_set_epi32(v4 as i32, v3 as i32, v2 as i32, v1 as i32) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: u32) -> u32x4 {
+        assert_supported();
+        unsafe { _mm_set1_epi32(v as i32) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[u32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[u32]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const u32) -> Self {
+        assert_supported();
+        _mm_loadu_si128(a as *const __m128i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[u32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[u32]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const u32) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_si128(a as *const __m128i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 4 and zeroes the rest, or 4 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[u32]) -> Self {
+        if a.len() >= 4 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm_maskload_epi32(a.as_ptr() as *const i32, mask_epi32x4(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[u32]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const u32) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_stream_load_si128(a as *const __m128i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> u32 { unsafe { _mm_extract_epi32::<IDX>(self.v) as u32 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: u32) -> Self { unsafe { _mm_insert_epi32::<IDX>(self.v, v as i32) }.into() }
+
+    #[inline]
+    pub fn to_raw_f32(self) -> f32x4 { unsafe { _mm_castsi128_ps(self.v) }.into() }
+
+    #[inline]
+    pub fn blend<const IMM: i32>(self, other: Self) -> Self {
+        unsafe { _mm_blend_epi32::<IMM>(self.v, other.v) }.into()
+    }
+
+    #[inline]
+    pub fn to_raw_i32(self) -> i32x4 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u16(self) -> u16x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u64(self) -> u64x2 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [u32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [u32]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut u32) { _mm_storeu_si128(a as *mut __m128i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [u32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [u32]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut u32) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_si128(a as *mut __m128i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 4, or 4 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [u32]) {
+        if a.len() >= 4 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm_maskstore_epi32(a.as_mut_ptr() as *mut i32, mask_epi32x4(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [u32]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut u32) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_si128(a as *mut __m128i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm_slli_epi32::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm_srli_epi32::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_si128(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { unsafe { _mm_min_epu32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { unsafe { _mm_max_epu32(self.v, other.v) }.into() }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> u32 {
+        unsafe {
+            let v = self.v;
+            let v = op(v, _mm_srli_si128::<8>(v));
+            _mm_cvtsi128_si32(op(v, _mm_srli_si128::<4>(v))) as u32
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> u32 { self.reduce(|a, b| unsafe { _mm_add_epi32(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> u64 {
+        u64x2::from(unsafe { _mm_cvtepu32_epi64(self.v) }).sum() +
+            u64x2::from(unsafe { _mm_cvtepu32_epi64(_mm_srli_si128::<8>(self.v)) }).sum()
+    }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> u32 { self.reduce(|a, b| unsafe { _mm_mullo_epi32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_min(self) -> u32 { self.reduce(|a, b| unsafe { _mm_min_epu32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_max(self) -> u32 { self.reduce(|a, b| unsafe { _mm_max_epu32(a, b) }) }
+
+    #[inline]
+    pub fn reduce_and(self) -> u32 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> u32 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> u32 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m32x4 { unsafe { _mm_cmpeq_epi32(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m32x4 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m32x4 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m32x4 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m32x4 {
+        unsafe {
+            let sign = _mm_set1_epi32(i32::MIN);
+            _mm_cmpgt_epi32(_mm_xor_si128(self.v, sign), _mm_xor_si128(other.v, sign))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m32x4 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m32x4, a: Self, b: Self) -> Self { unsafe { _mm_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> u32 { unsafe { _mm_extract_epi32::<INDEX>(self.v) as u32 } }
+
+    #[inline]
+    pub fn to_array(self) -> [u32; 4] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[u32; 4] { unsafe { &*(self as *const Self as *const [u32; 4]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> u32 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: u32) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, u32> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> u32>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle128::<S, 4>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle128::<S, 4>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<4>>() }
+
+    /// Lane `(i + N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<4, N>>() }
+
+    /// Lane `(i - N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<4, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left128::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right128::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<4, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<4>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<4>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<4>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<4>>(other) }
+}
+
+impl Default for u32x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for u32x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { u32x4::eq(*self, *other).all() }
+}
+
+impl Eq for u32x4 {}
+
+impl Hash for u32x4 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[u32; 4]> for u32x4 {
+    #[inline]
+    fn from(a: [u32; 4]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<u32x4> for [u32; 4] {
+    #[inline]
+    fn from(v: u32x4) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for u32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("u32x4").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for u32x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for u32x4 {
+    type Output = u32;
+
+    #[inline]
+    fn index(&self, i: usize) -> &u32 { &self.as_array()[i] }
+}
+
+impl IntoIterator for u32x4 {
+    type Item = u32;
+    type IntoIter = array::IntoIter<u32, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a u32x4 {
+    type Item = &'a u32;
+    type IntoIter = slice::Iter<'a, u32>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128i> for u32x4 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<u32x4> for __m128i {
+    #[inline]
+    fn from(v: u32x4) -> Self { v.v }
+}
+
+impl Mul<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn mul(self, rhs: u32x4) -> Self::Output { unsafe { _mm_mullo_epi32(self.v, rhs.v) }.into() }
+}
+
+impl MulAssign<u32x4> for u32x4 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u32x4) { self.v = (*self * rhs).v }
+}
+
+impl Add<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn add(self, rhs: u32x4) -> Self::Output { unsafe { _mm_add_epi32(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for u32x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn sub(self, rhs: u32x4) -> Self::Output { unsafe { _mm_sub_epi32(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for u32x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn bitand(self, rhs: u32x4) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for u32x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn bitor(self, rhs: u32x4) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for u32x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn bitxor(self, rhs: u32x4) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for u32x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm_sll_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for u32x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm_srl_epi32(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for u32x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn shl(self, rhs: u32x4) -> Self::Output { unsafe { _mm_sllv_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<u32x4> for u32x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u32x4) { self.v = (*self << rhs).v }
+}
+
+impl Shr<u32x4> for u32x4 {
+    type Output = u32x4;
+
+    #[inline]
+    fn shr(self, rhs: u32x4) -> Self::Output { unsafe { _mm_srlv_epi32(self.v, rhs.v) }.into() }
+}
+
+impl ShrAssign<u32x4> for u32x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u32x4) { self.v = (*self >> rhs).v }
+}
@@ -0,0 +1,1143 @@
+use crate::{
+    aligned::is_aligned, arch::*, bounds::OutOfBounds, detect::assert_supported, i64x2, i64x4, lanes::fmt_lanes, m64x2,
+    m64x4, partial::mask_epi64x2, partial::mask_epi64x4, reduce::first_lane, swizzle::shift_left128,
+    swizzle::shift_left256, swizzle::shift_right128, swizzle::shift_right256, swizzle::shuffle128, swizzle::shuffle256,
+    swizzle::swizzle128, swizzle::swizzle256, swizzle::Broadcast, swizzle::DeinterleaveEven, swizzle::DeinterleaveOdd,
+    swizzle::InterleaveHi, swizzle::InterleaveLo, swizzle::Reverse, swizzle::RotateLeft, swizzle::RotateRight,
+    swizzle::Swizzle, u16x16, u16x8, u32x4, u32x8,
+};
+use std::{
+    array, fmt,
+    hash::{Hash, Hasher},
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Index, Not, Shl, ShlAssign,
+        Shr, ShrAssign, Sub, SubAssign,
+    },
+    slice,
+};
+
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct u64x4 {
+    v: __m256i,
+}
+
+impl u64x4 {
+    #[inline]
+    pub fn new(v1: u64, v2: u64, v3: u64, v4: u64) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm256_set_epi64x(v4 as i64, v3 as i64, v2 as i64, v1 as i64) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: u64) -> u64x4 {
+        assert_supported();
+        unsafe { _mm256_set1_epi64x(v as i64) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[u64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[u64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const u64) -> Self {
+        assert_supported();
+        _mm256_loadu_si256(a as *const __m256i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[u64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[u64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const u64) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_load_si256(a as *const __m256i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 4 and zeroes the rest, or 4 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[u64]) -> Self {
+        if a.len() >= 4 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm256_maskload_epi64(a.as_ptr() as *const i64, mask_epi64x4(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[u64]) -> Self {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const u64) -> Self {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        assert_supported();
+        _mm256_stream_load_si256(a as *const __m256i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> u64 { unsafe { _mm256_extract_epi64::<IDX>(self.v) as u64 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: u64) -> Self { unsafe { _mm256_insert_epi64::<IDX>(self.v, v as i64) }.into() }
+
+    #[inline]
+    pub fn permute<const IMM: i32>(self) -> Self { unsafe { _mm256_permute4x64_epi64::<IMM>(self.v) }.into() }
+
+    #[inline]
+    pub unsafe fn gather_ptr<const SCALE: i32>(a: *const u64, idx: i64x4) -> Self {
+        _mm256_i64gather_epi64::<SCALE>(a as *const i64, idx.into()).into()
+    }
+
+    #[inline]
+    pub unsafe fn gather_unchecked<const SCALE: i32>(a: &[u64], idx: i64x4) -> Self {
+        Self::gather_ptr::<SCALE>(a.as_ptr(), idx)
+    }
+
+    /// Gathers `a[idx[i]]` into each lane, panics if an index is out of bounds.
+    #[inline]
+    pub fn gather(a: &[u64], idx: i64x4) -> Self {
+        if idx.out_of_bounds(a.len()).any() {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_unchecked::<8>(a, idx) }
+    }
+
+    /// [`gather`](Self::gather) returning the lanes with out of bounds indices as an error.
+    #[inline]
+    pub fn try_gather(a: &[u64], idx: i64x4) -> Result<Self, OutOfBounds> {
+        let oob = idx.out_of_bounds(a.len());
+
+        if oob.any() {
+            return Err(OutOfBounds {
+                lanes: oob.to_bitmask() as u32,
+            });
+        }
+
+        Ok(unsafe { Self::gather_unchecked::<8>(a, idx) })
+    }
+
+    /// Gathers `a[idx[i]]` into the lanes set in `mask` and takes the other lanes from `default`, panics if an index
+    /// of a set lane is out of bounds.
+    #[inline]
+    pub fn gather_masked(a: &[u64], idx: i64x4, mask: m64x4, default: Self) -> Self {
+        if idx.out_of_bounds(a.len()).to_bitmask() & mask.to_bitmask() != 0 {
+            panic!("Index out of bounds!")
+        }
+
+        unsafe { Self::gather_masked_ptr::<8>(a.as_ptr(), idx, mask, default) }
+    }
+
+    /// Only reads the addresses of the lanes set in `mask`.
+    #[inline]
+    pub unsafe fn gather_masked_ptr<const SCALE: i32>(a: *const u64, idx: i64x4, mask: m64x4, default: Self) -> Self {
+        _mm256_mask_i64gather_epi64::<SCALE>(default.v, a as *const i64, idx.into(), mask.into()).into()
+    }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x4 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u16(self) -> u16x16 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u32(self) -> u32x8 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [u64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [u64]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut u64) { _mm256_storeu_si256(a as *mut __m256i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 32 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [u64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [u64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 32 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut u64) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_store_si256(a as *mut __m256i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 4, or 4 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [u64]) {
+        if a.len() >= 4 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm256_maskstore_epi64(a.as_mut_ptr() as *mut i64, mask_epi64x4(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [u64]) {
+        if a.len() < 4 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 32) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut u64) {
+        debug_assert!(is_aligned(a, 32), "Pointer not aligned!");
+        _mm256_stream_si256(a as *mut __m256i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm256_slli_epi64::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm256_srli_epi64::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm256_andnot_si256(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::select(self.lt(other), self, other) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::select(self.gt(other), self, other) }
+
+    #[inline]
+    fn reduce(self, op: impl Fn(__m128i, __m128i) -> __m128i) -> u64 {
+        unsafe {
+            let v = op(_mm256_castsi256_si128(self.v), _mm256_extracti128_si256::<1>(self.v));
+            _mm_cvtsi128_si64(op(v, _mm_srli_si128::<8>(v))) as u64
+        }
+    }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> u64 { self.reduce(|a, b| unsafe { _mm_add_epi64(a, b) }) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> u128 { self.lo().sum_widening() + self.hi().sum_widening() }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> u64 { self.lo().product().wrapping_mul(self.hi().product()) }
+
+    #[inline]
+    pub fn reduce_min(self) -> u64 { self.reduce(|a, b| u64x2::from(a).min(b.into()).into()) }
+
+    #[inline]
+    pub fn reduce_max(self) -> u64 { self.reduce(|a, b| u64x2::from(a).max(b.into()).into()) }
+
+    #[inline]
+    pub fn reduce_and(self) -> u64 { self.reduce(|a, b| unsafe { _mm_and_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_or(self) -> u64 { self.reduce(|a, b| unsafe { _mm_or_si128(a, b) }) }
+
+    #[inline]
+    pub fn reduce_xor(self) -> u64 { self.reduce(|a, b| unsafe { _mm_xor_si128(a, b) }) }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x4 { unsafe { _mm256_cmpeq_epi64(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x4 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x4 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x4 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x4 {
+        unsafe {
+            let sign = _mm256_set1_epi64x(i64::MIN);
+            _mm256_cmpgt_epi64(_mm256_xor_si256(self.v, sign), _mm256_xor_si256(other.v, sign))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x4 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m64x4, a: Self, b: Self) -> Self { unsafe { _mm256_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn lo(self) -> u64x2 { unsafe { _mm256_castsi256_si128(self.v) }.into() }
+
+    #[inline]
+    pub fn hi(self) -> u64x2 { unsafe { _mm256_extracti128_si256::<1>(self.v) }.into() }
+
+    #[inline]
+    pub fn concat(lo: u64x2, hi: u64x2) -> Self { unsafe { _mm256_set_m128i(hi.into(), lo.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> u64 { unsafe { _mm256_extract_epi64::<INDEX>(self.v) as u64 } }
+
+    #[inline]
+    pub fn to_array(self) -> [u64; 4] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[u64; 4] { unsafe { &*(self as *const Self as *const [u64; 4]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> u64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: u64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, u64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> u64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle256::<S, 4>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle256::<S, 4>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<4>>() }
+
+    /// Lane `(i + N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<4, N>>() }
+
+    /// Lane `(i - N) % 4` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<4, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left256::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 4.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right256::<4, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<4, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating, across the full width unlike `unpacklo`.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<4>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating, across the full width unlike `unpackhi`.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<4>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<4>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<4>>(other) }
+}
+
+impl Default for u64x4 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for u64x4 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { u64x4::eq(*self, *other).all() }
+}
+
+impl Eq for u64x4 {}
+
+impl Hash for u64x4 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[u64; 4]> for u64x4 {
+    #[inline]
+    fn from(a: [u64; 4]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<u64x4> for [u64; 4] {
+    #[inline]
+    fn from(v: u64x4) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for u64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("u64x4").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for u64x4 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for u64x4 {
+    type Output = u64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &u64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for u64x4 {
+    type Item = u64;
+    type IntoIter = array::IntoIter<u64, 4>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a u64x4 {
+    type Item = &'a u64;
+    type IntoIter = slice::Iter<'a, u64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m256i> for u64x4 {
+    #[inline]
+    fn from(v: __m256i) -> Self { Self { v } }
+}
+
+impl From<u64x4> for __m256i {
+    #[inline]
+    fn from(v: u64x4) -> Self { v.v }
+}
+
+impl Add<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn add(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_add_epi64(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for u64x4 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn sub(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_sub_epi64(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for u64x4 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn bitand(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_and_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for u64x4 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn bitor(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_or_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for u64x4 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn bitxor(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_xor_si256(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for u64x4 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm256_xor_si256(self.v, _mm256_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm256_sll_epi64(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for u64x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm256_srl_epi64(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for u64x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn shl(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_sllv_epi64(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<u64x4> for u64x4 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64x4) { self.v = (*self << rhs).v }
+}
+
+impl Shr<u64x4> for u64x4 {
+    type Output = u64x4;
+
+    #[inline]
+    fn shr(self, rhs: u64x4) -> Self::Output { unsafe { _mm256_srlv_epi64(self.v, rhs.v) }.into() }
+}
+
+impl ShrAssign<u64x4> for u64x4 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64x4) { self.v = (*self >> rhs).v }
+}
+
+/// The 128-bit counterpart of [`u64x4`]. Gathers, `permute` and `lo`/`hi`/`concat` are 256-bit only.
+#[allow(non_camel_case_types)]
+#[derive(Copy, Clone)]
+#[repr(transparent)]
+pub struct u64x2 {
+    v: __m128i,
+}
+
+impl u64x2 {
+    #[inline]
+    pub fn new(v1: u64, v2: u64) -> Self {
+        assert_supported();
+        Self {
+            v: unsafe { _mm_set_epi64x(v2 as i64, v1 as i64) },
+        }
+    }
+
+    #[inline]
+    pub fn splat(v: u64) -> u64x2 {
+        assert_supported();
+        unsafe { _mm_set1_epi64x(v as i64) }.into()
+    }
+
+    #[inline]
+    pub fn from_slice(a: &[u64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { Self::from_slice_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn from_slice_unchecked(a: &[u64]) -> Self { Self::from_ptr(a.as_ptr()) }
+
+    #[inline]
+    pub unsafe fn from_ptr(a: *const u64) -> Self {
+        assert_supported();
+        _mm_loadu_si128(a as *const __m128i).into()
+    }
+
+    /// [`from_slice`](Self::from_slice) for a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn load_aligned(a: &[u64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::load_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn load_aligned_unchecked(a: &[u64]) -> Self { Self::from_ptr_aligned(a.as_ptr()) }
+
+    /// [`from_ptr`](Self::from_ptr) for a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn from_ptr_aligned(a: *const u64) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_load_si128(a as *const __m128i).into()
+    }
+
+    /// Loads the first `a.len()` lanes of a slice shorter than 2 and zeroes the rest, or 2 lanes like
+    /// [`from_slice`](Self::from_slice).
+    #[inline]
+    pub fn load_partial(a: &[u64]) -> Self {
+        if a.len() >= 2 {
+            return unsafe { Self::from_slice_unchecked(a) };
+        }
+
+        assert_supported();
+        unsafe { _mm_maskload_epi64(a.as_ptr() as *const i64, mask_epi64x2(a.len())) }.into()
+    }
+
+    /// [`load_aligned`](Self::load_aligned) with a non-temporal hint, which only bypasses the cache when reading
+    /// write-combining memory.
+    #[inline]
+    pub fn load_stream(a: &[u64]) -> Self {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { Self::from_ptr_stream(a.as_ptr()) }
+    }
+
+    /// [`from_ptr_aligned`](Self::from_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn from_ptr_stream(a: *const u64) -> Self {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        assert_supported();
+        _mm_stream_load_si128(a as *const __m128i).into()
+    }
+
+    #[inline]
+    pub fn get<const IDX: i32>(self) -> u64 { unsafe { _mm_extract_epi64::<IDX>(self.v) as u64 } }
+
+    #[inline]
+    pub fn set<const IDX: i32>(self, v: u64) -> Self { unsafe { _mm_insert_epi64::<IDX>(self.v, v as i64) }.into() }
+
+    #[inline]
+    pub fn to_raw_i64(self) -> i64x2 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u16(self) -> u16x8 { self.v.into() }
+
+    #[inline]
+    pub fn to_raw_u32(self) -> u32x4 { self.v.into() }
+
+    #[inline]
+    pub fn store(self, a: &mut [u64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        unsafe { self.store_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_unchecked(self, a: &mut [u64]) { self.store_ptr(a.as_mut_ptr()) }
+
+    #[inline]
+    pub unsafe fn store_ptr(self, a: *mut u64) { _mm_storeu_si128(a as *mut __m128i, self.v) }
+
+    /// [`store`](Self::store) to a slice aligned to 16 bytes, panics if it isn't.
+    #[inline]
+    pub fn store_aligned(self, a: &mut [u64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_aligned_unchecked(a) }
+    }
+
+    #[inline]
+    pub unsafe fn store_aligned_unchecked(self, a: &mut [u64]) { self.store_ptr_aligned(a.as_mut_ptr()) }
+
+    /// [`store_ptr`](Self::store_ptr) to a pointer aligned to 16 bytes, only checked in debug builds.
+    #[inline]
+    pub unsafe fn store_ptr_aligned(self, a: *mut u64) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_store_si128(a as *mut __m128i, self.v)
+    }
+
+    /// Stores the first `a.len()` lanes into a slice shorter than 2, or 2 lanes like [`store`](Self::store).
+    #[inline]
+    pub fn store_partial(self, a: &mut [u64]) {
+        if a.len() >= 2 {
+            return unsafe { self.store_unchecked(a) };
+        }
+
+        unsafe { _mm_maskstore_epi64(a.as_mut_ptr() as *mut i64, mask_epi64x2(a.len()), self.v) }
+    }
+
+    /// [`store_aligned`](Self::store_aligned) with a non-temporal hint that writes around the cache, for large
+    /// outputs that aren't read again soon. Call [`sfence`](crate::stream::sfence) before other threads read them.
+    #[inline]
+    pub fn store_stream(self, a: &mut [u64]) {
+        if a.len() < 2 {
+            panic!("Slice too small!")
+        }
+
+        if !is_aligned(a.as_ptr(), 16) {
+            panic!("Slice not aligned!")
+        }
+
+        unsafe { self.store_ptr_stream(a.as_mut_ptr()) }
+    }
+
+    /// [`store_ptr_aligned`](Self::store_ptr_aligned) with a non-temporal hint.
+    #[inline]
+    pub unsafe fn store_ptr_stream(self, a: *mut u64) {
+        debug_assert!(is_aligned(a, 16), "Pointer not aligned!");
+        _mm_stream_si128(a as *mut __m128i, self.v)
+    }
+
+    #[inline]
+    pub fn shl<const N: i32>(self) -> Self { unsafe { _mm_slli_epi64::<N>(self.v) }.into() }
+
+    #[inline]
+    pub fn shr_logical<const N: i32>(self) -> Self { unsafe { _mm_srli_epi64::<N>(self.v) }.into() }
+
+    /// `self & !other`
+    #[inline]
+    pub fn andnot(self, other: Self) -> Self { unsafe { _mm_andnot_si128(other.v, self.v) }.into() }
+
+    #[inline]
+    pub fn min(self, other: Self) -> Self { Self::select(self.lt(other), self, other) }
+
+    #[inline]
+    pub fn max(self, other: Self) -> Self { Self::select(self.gt(other), self, other) }
+
+    /// Sum of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn sum(self) -> u64 { self.get::<0>().wrapping_add(self.get::<1>()) }
+
+    /// Sum of all lanes, widened so it cannot overflow.
+    #[inline]
+    pub fn sum_widening(self) -> u128 { self.get::<0>() as u128 + self.get::<1>() as u128 }
+
+    /// Product of all lanes, wrapping on overflow.
+    #[inline]
+    pub fn product(self) -> u64 { self.get::<0>().wrapping_mul(self.get::<1>()) }
+
+    #[inline]
+    pub fn reduce_min(self) -> u64 { self.get::<0>().min(self.get::<1>()) }
+
+    #[inline]
+    pub fn reduce_max(self) -> u64 { self.get::<0>().max(self.get::<1>()) }
+
+    #[inline]
+    pub fn reduce_and(self) -> u64 { self.get::<0>() & self.get::<1>() }
+
+    #[inline]
+    pub fn reduce_or(self) -> u64 { self.get::<0>() | self.get::<1>() }
+
+    #[inline]
+    pub fn reduce_xor(self) -> u64 { self.get::<0>() ^ self.get::<1>() }
+
+    /// Index of the first lane holding the minimum.
+    #[inline]
+    pub fn argmin(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_min())).to_bitmask() as u32) }
+
+    /// Index of the first lane holding the maximum.
+    #[inline]
+    pub fn argmax(self) -> usize { first_lane(self.eq(Self::splat(self.reduce_max())).to_bitmask() as u32) }
+
+    #[inline]
+    pub fn eq(self, other: Self) -> m64x2 { unsafe { _mm_cmpeq_epi64(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn ne(self, other: Self) -> m64x2 { !self.eq(other) }
+
+    #[inline]
+    pub fn lt(self, other: Self) -> m64x2 { other.gt(self) }
+
+    #[inline]
+    pub fn le(self, other: Self) -> m64x2 { !self.gt(other) }
+
+    #[inline]
+    pub fn gt(self, other: Self) -> m64x2 {
+        unsafe {
+            let sign = _mm_set1_epi64x(i64::MIN);
+            _mm_cmpgt_epi64(_mm_xor_si128(self.v, sign), _mm_xor_si128(other.v, sign))
+        }
+        .into()
+    }
+
+    #[inline]
+    pub fn ge(self, other: Self) -> m64x2 { !other.gt(self) }
+
+    #[inline]
+    pub fn select(mask: m64x2, a: Self, b: Self) -> Self { unsafe { _mm_blendv_epi8(b.v, a.v, mask.into()) }.into() }
+
+    #[inline]
+    pub fn extract<const INDEX: i32>(self) -> u64 { unsafe { _mm_extract_epi64::<INDEX>(self.v) as u64 } }
+
+    #[inline]
+    pub fn to_array(self) -> [u64; 2] { *self.as_array() }
+
+    /// The lanes in the order of the [`new`](Self::new) arguments.
+    #[inline]
+    pub fn as_array(&self) -> &[u64; 2] { unsafe { &*(self as *const Self as *const [u64; 2]) } }
+
+    /// Lane `i` in the order of the [`new`](Self::new) arguments, panics if `i` is out of range.
+    #[inline]
+    pub fn lane(self, i: usize) -> u64 { self.as_array()[i] }
+
+    /// Copy with lane `i` replaced by `v`, panics if `i` is out of range.
+    #[inline]
+    pub fn replace_lane(self, i: usize, v: u64) -> Self {
+        let mut a = self.to_array();
+        a[i] = v;
+        Self::from(a)
+    }
+
+    #[inline]
+    pub fn iter(&self) -> slice::Iter<'_, u64> { self.as_array().iter() }
+
+    /// Builds a vector from `f(i)` for each lane `i`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> u64>(f: F) -> Self { Self::from(array::from_fn(f)) }
+
+    /// Lanes picked by `S::INDEX`, see [`swizzle!`](crate::swizzle!).
+    #[inline]
+    pub fn swizzle<S: Swizzle>(self) -> Self { unsafe { swizzle128::<S, 2>(self.v) }.into() }
+
+    /// Lanes of `self` followed by those of `other`, picked by `S::INDEX`, see [`shuffle!`](crate::shuffle!).
+    #[inline]
+    pub fn shuffle2<S: Swizzle>(self, other: Self) -> Self { unsafe { shuffle128::<S, 2>(self.v, other.v) }.into() }
+
+    #[inline]
+    pub fn reverse(self) -> Self { self.swizzle::<Reverse<2>>() }
+
+    /// Lane `(i + N) % 2` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_left<const N: usize>(self) -> Self { self.swizzle::<RotateLeft<2, N>>() }
+
+    /// Lane `(i - N) % 2` for each lane `i`.
+    #[inline]
+    pub fn rotate_lanes_right<const N: usize>(self) -> Self { self.swizzle::<RotateRight<2, N>>() }
+
+    /// Lanes `N..` of `self` followed by the first `N` lanes of `fill`, fails to compile for `N` above 2.
+    #[inline]
+    pub fn shift_lanes_left<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_left128::<2, N>(self.v, fill.v) }.into()
+    }
+
+    /// The last `N` lanes of `fill` followed by the first lanes of `self`, fails to compile for `N` above 2.
+    #[inline]
+    pub fn shift_lanes_right<const N: usize>(self, fill: Self) -> Self {
+        unsafe { shift_right128::<2, N>(self.v, fill.v) }.into()
+    }
+
+    /// Lane `I` in every lane.
+    #[inline]
+    pub fn broadcast_lane<const I: usize>(self) -> Self { self.swizzle::<Broadcast<2, I>>() }
+
+    /// The lower halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_lo(self, other: Self) -> Self { self.shuffle2::<InterleaveLo<2>>(other) }
+
+    /// The upper halves of `self` and `other` with their lanes alternating.
+    #[inline]
+    pub fn interleave_hi(self, other: Self) -> Self { self.shuffle2::<InterleaveHi<2>>(other) }
+
+    /// The even lanes of `self` followed by the even lanes of `other`.
+    #[inline]
+    pub fn deinterleave_even(self, other: Self) -> Self { self.shuffle2::<DeinterleaveEven<2>>(other) }
+
+    /// The odd lanes of `self` followed by the odd lanes of `other`.
+    #[inline]
+    pub fn deinterleave_odd(self, other: Self) -> Self { self.shuffle2::<DeinterleaveOdd<2>>(other) }
+}
+
+impl Default for u64x2 {
+    #[inline]
+    fn default() -> Self { Self::splat(0) }
+}
+
+impl PartialEq for u64x2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { u64x2::eq(*self, *other).all() }
+}
+
+impl Eq for u64x2 {}
+
+impl Hash for u64x2 {
+    fn hash<H: Hasher>(&self, state: &mut H) { self.as_array().hash(state) }
+}
+
+impl From<[u64; 2]> for u64x2 {
+    #[inline]
+    fn from(a: [u64; 2]) -> Self { Self::from_slice(&a) }
+}
+
+impl From<u64x2> for [u64; 2] {
+    #[inline]
+    fn from(v: u64x2) -> Self { v.to_array() }
+}
+
+impl fmt::Debug for u64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { f.debug_tuple("u64x2").field(self.as_array()).finish() }
+}
+
+impl fmt::Display for u64x2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { fmt_lanes(self.as_array(), f) }
+}
+
+impl Index<usize> for u64x2 {
+    type Output = u64;
+
+    #[inline]
+    fn index(&self, i: usize) -> &u64 { &self.as_array()[i] }
+}
+
+impl IntoIterator for u64x2 {
+    type Item = u64;
+    type IntoIter = array::IntoIter<u64, 2>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.to_array().into_iter() }
+}
+
+impl<'a> IntoIterator for &'a u64x2 {
+    type Item = &'a u64;
+    type IntoIter = slice::Iter<'a, u64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+impl From<__m128i> for u64x2 {
+    #[inline]
+    fn from(v: __m128i) -> Self { Self { v } }
+}
+
+impl From<u64x2> for __m128i {
+    #[inline]
+    fn from(v: u64x2) -> Self { v.v }
+}
+
+impl Add<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn add(self, rhs: u64x2) -> Self::Output { unsafe { _mm_add_epi64(self.v, rhs.v) }.into() }
+}
+
+impl AddAssign for u64x2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) { self.v = (*self + rhs).v }
+}
+
+impl Sub<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn sub(self, rhs: u64x2) -> Self::Output { unsafe { _mm_sub_epi64(self.v, rhs.v) }.into() }
+}
+
+impl SubAssign for u64x2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) { self.v = (*self - rhs).v }
+}
+
+impl BitAnd<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn bitand(self, rhs: u64x2) -> Self::Output { unsafe { _mm_and_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitAndAssign for u64x2 {
+    #[inline]
+    fn bitand_assign(&mut self, rhs: Self) { self.v = (*self & rhs).v }
+}
+
+impl BitOr<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn bitor(self, rhs: u64x2) -> Self::Output { unsafe { _mm_or_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitOrAssign for u64x2 {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) { self.v = (*self | rhs).v }
+}
+
+impl BitXor<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn bitxor(self, rhs: u64x2) -> Self::Output { unsafe { _mm_xor_si128(self.v, rhs.v) }.into() }
+}
+
+impl BitXorAssign for u64x2 {
+    #[inline]
+    fn bitxor_assign(&mut self, rhs: Self) { self.v = (*self ^ rhs).v }
+}
+
+impl Not for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn not(self) -> Self::Output { unsafe { _mm_xor_si128(self.v, _mm_set1_epi32(-1)) }.into() }
+}
+
+impl Shl<i32> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn shl(self, rhs: i32) -> Self::Output { unsafe { _mm_sll_epi64(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShlAssign<i32> for u64x2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: i32) { self.v = (*self << rhs).v }
+}
+
+impl Shr<i32> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn shr(self, rhs: i32) -> Self::Output { unsafe { _mm_srl_epi64(self.v, _mm_cvtsi32_si128(rhs)) }.into() }
+}
+
+impl ShrAssign<i32> for u64x2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: i32) { self.v = (*self >> rhs).v }
+}
+
+impl Shl<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn shl(self, rhs: u64x2) -> Self::Output { unsafe { _mm_sllv_epi64(self.v, rhs.v) }.into() }
+}
+
+impl ShlAssign<u64x2> for u64x2 {
+    #[inline]
+    fn shl_assign(&mut self, rhs: u64x2) { self.v = (*self << rhs).v }
+}
+
+impl Shr<u64x2> for u64x2 {
+    type Output = u64x2;
+
+    #[inline]
+    fn shr(self, rhs: u64x2) -> Self::Output { unsafe { _mm_srlv_epi64(self.v, rhs.v) }.into() }
+}
+
+impl ShrAssign<u64x2> for u64x2 {
+    #[inline]
+    fn shr_assign(&mut self, rhs: u64x2) { self.v = (*self >> rhs).v }
+}
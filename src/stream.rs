@@ -0,0 +1,55 @@
+//! Cache control for streaming through buffers larger than the cache, used with the `load_stream` and
+//! `store_stream` vector methods.
+
+use crate::arch::*;
+
+/// Orders all earlier stores before later ones. Non-temporal stores are weakly ordered, so call this after a
+/// sequence of `store_stream` before publishing the data to other threads.
+#[inline]
+pub fn sfence() { unsafe { _mm_sfence() } }
+
+/// Hints the CPU to fetch the cache line containing `p` into the cache level given by `H`. Never faults, `p` may
+/// be dangling or null.
+#[allow(clippy::not_unsafe_ptr_arg_deref)] // Prefetches never fault
+#[inline]
+pub fn prefetch<H: PrefetchHint>(p: *const impl ?Sized) { H::prefetch(p.cast()) }
+
+/// Cache level selector for [`prefetch`], one of [`T0`], [`T1`], [`T2`] and [`NTA`].
+pub trait PrefetchHint: private::Sealed {
+    #[doc(hidden)]
+    fn prefetch(p: *const i8);
+}
+
+/// Prefetch into all cache levels.
+#[derive(Debug, Copy, Clone)]
+pub struct T0;
+
+/// Prefetch into L2 and outer levels.
+#[derive(Debug, Copy, Clone)]
+pub struct T1;
+
+/// Prefetch into L3 and outer levels.
+#[derive(Debug, Copy, Clone)]
+pub struct T2;
+
+/// Prefetch close to the core with minimal cache pollution, for data read only once.
+#[derive(Debug, Copy, Clone)]
+pub struct NTA;
+
+macro_rules! prefetch_hint {
+    ($($hint:ident => $strategy:ident),*) => {$(
+        impl private::Sealed for $hint {}
+
+        impl PrefetchHint for $hint {
+            #[allow(clippy::not_unsafe_ptr_arg_deref)]
+            #[inline(always)]
+            fn prefetch(p: *const i8) { unsafe { _mm_prefetch::<$strategy>(p) } }
+        }
+    )*};
+}
+
+prefetch_hint!(T0 => _MM_HINT_T0, T1 => _MM_HINT_T1, T2 => _MM_HINT_T2, NTA => _MM_HINT_NTA);
+
+mod private {
+    pub trait Sealed {}
+}
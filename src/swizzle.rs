@@ -0,0 +1,327 @@
+//! Lane shuffles by logical lane index, checked at compile time.
+//!
+//! The indices are turned into constant byte masks when the method is instantiated. Lanes that stay in place are
+//! blended, 32 and 64-bit lanes are moved with `permutevar8x32` and 8 and 16-bit lanes with `shuffle_epi8` on the
+//! vector and its swapped 128-bit halves. As the masks are constants the compiler further lowers them to `permute`,
+//! `shuffle`, `unpack` or `blend` immediates where the pattern allows.
+
+use crate::arch::*;
+
+/// Lane indices for the `swizzle` and `shuffle2` methods, in the order of the `new` arguments. For `shuffle2` the
+/// lanes of the second vector follow those of the first. Usually defined through [`swizzle!`](crate::swizzle!) and
+/// [`shuffle!`](crate::shuffle!).
+pub trait Swizzle {
+    const INDEX: &'static [usize];
+}
+
+/// `swizzle!(v, [i0, i1, ...])` picks lane `i0` of `v` for the first lane and so on. Wrong index counts and indices
+/// out of range fail to compile.
+#[macro_export]
+macro_rules! swizzle {
+    ($v:expr, [$($i:expr),+ $(,)?]) => {{
+        struct SwizzleIndex;
+
+        impl $crate::Swizzle for SwizzleIndex {
+            const INDEX: &'static [usize] = &[$($i),+];
+        }
+
+        $v.swizzle::<SwizzleIndex>()
+    }};
+}
+
+/// `shuffle!(a, b, [i0, i1, ...])` picks lanes of `a` for indices below the lane count and lanes of `b` above. Wrong
+/// index counts and indices out of range fail to compile.
+#[macro_export]
+macro_rules! shuffle {
+    ($a:expr, $b:expr, [$($i:expr),+ $(,)?]) => {{
+        struct SwizzleIndex;
+
+        impl $crate::Swizzle for SwizzleIndex {
+            const INDEX: &'static [usize] = &[$($i),+];
+        }
+
+        $a.shuffle2::<SwizzleIndex>($b)
+    }};
+}
+
+// For every output byte the byte of the concatenated sources it is taken from
+const fn byte_map(index: &[usize], lanes: usize, sources: usize, bytes: usize) -> [usize; 64] {
+    if index.len() != lanes {
+        panic!("Wrong number of lane indices!");
+    }
+
+    let width = bytes / lanes;
+    let mut map = [0; 64];
+    let mut i = 0;
+
+    while i < lanes {
+        if index[i] >= lanes * sources {
+            panic!("Lane index out of range!");
+        }
+
+        let mut j = 0;
+
+        while j < width {
+            map[i * width + j] = index[i] * width + j;
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    map
+}
+
+struct Plan128 {
+    used:     [bool; 2],
+    in_place: [bool; 2],
+    sel:      [[i8; 16]; 2],
+    shuf:     [[i8; 16]; 2],
+}
+
+const fn plan128(map: &[usize; 64]) -> Plan128 {
+    let mut p = Plan128 {
+        used:     [false; 2],
+        in_place: [true; 2],
+        sel:      [[0; 16]; 2],
+        shuf:     [[-128; 16]; 2],
+    };
+    let mut j = 0;
+
+    while j < 16 {
+        let (k, s) = (map[j] / 16, map[j] % 16);
+        p.used[k] = true;
+        p.in_place[k] &= s == j;
+        p.sel[k][j] = -1;
+        p.shuf[k][j] = s as i8;
+        j += 1;
+    }
+
+    p
+}
+
+// Plan for 32 output bytes starting at `off`, taken from up to four 256-bit sources
+struct Plan256 {
+    used:     [bool; 4],
+    in_place: [bool; 4],
+    sel:      [[i8; 32]; 4],
+    dwords:   [[i32; 8]; 4],
+    direct:   [[i8; 32]; 4],
+    swapped:  [[i8; 32]; 4],
+    cross:    [bool; 4],
+}
+
+const fn plan256(map: &[usize; 64], off: usize) -> Plan256 {
+    let mut p = Plan256 {
+        used:     [false; 4],
+        in_place: [true; 4],
+        sel:      [[0; 32]; 4],
+        dwords:   [[0; 8]; 4],
+        direct:   [[-128; 32]; 4],
+        swapped:  [[-128; 32]; 4],
+        cross:    [false; 4],
+    };
+    let mut j = 0;
+
+    while j < 32 {
+        let (k, s) = (map[off + j] / 32, map[off + j] % 32);
+        p.used[k] = true;
+        p.in_place[k] &= s == j;
+        p.sel[k][j] = -1;
+        p.dwords[k][j / 4] = (s / 4) as i32;
+
+        if s / 16 == j / 16 {
+            p.direct[k][j] = (s % 16) as i8;
+        } else {
+            p.swapped[k][j] = (s % 16) as i8;
+            p.cross[k] = true;
+        }
+
+        j += 1;
+    }
+
+    p
+}
+
+// Blends the lanes taken from source `k` into `r`, `r` is ignored for the first used source
+#[inline(always)]
+unsafe fn apply128(p: &Plan128, k: usize, src: __m128i, r: Option<__m128i>) -> Option<__m128i> {
+    if !p.used[k] {
+        return r;
+    }
+
+    let v =
+        if p.in_place[k] { src } else { _mm_shuffle_epi8(src, _mm_loadu_si128(p.shuf[k].as_ptr() as *const __m128i)) };
+
+    Some(match r {
+        Some(r) => _mm_blendv_epi8(r, v, _mm_loadu_si128(p.sel[k].as_ptr() as *const __m128i)),
+        None => v,
+    })
+}
+
+#[inline(always)]
+unsafe fn apply256(p: &Plan256, k: usize, src: __m256i, dwords: bool, r: Option<__m256i>) -> Option<__m256i> {
+    if !p.used[k] {
+        return r;
+    }
+
+    let v = if p.in_place[k] {
+        src
+    } else if dwords {
+        _mm256_permutevar8x32_epi32(src, _mm256_loadu_si256(p.dwords[k].as_ptr() as *const __m256i))
+    } else {
+        let v = _mm256_shuffle_epi8(src, _mm256_loadu_si256(p.direct[k].as_ptr() as *const __m256i));
+
+        if p.cross[k] {
+            let swapped = _mm256_permute2x128_si256::<0x01>(src, src);
+            let idx = _mm256_loadu_si256(p.swapped[k].as_ptr() as *const __m256i);
+            _mm256_or_si256(v, _mm256_shuffle_epi8(swapped, idx))
+        } else {
+            v
+        }
+    };
+
+    Some(match r {
+        Some(r) => _mm256_blendv_epi8(r, v, _mm256_loadu_si256(p.sel[k].as_ptr() as *const __m256i)),
+        None => v,
+    })
+}
+
+#[inline(always)]
+pub(crate) unsafe fn swizzle128<S: Swizzle, const LANES: usize>(a: __m128i) -> __m128i {
+    let p = const { &plan128(&byte_map(S::INDEX, LANES, 1, 16)) };
+    apply128(p, 0, a, None).unwrap_unchecked()
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shuffle128<S: Swizzle, const LANES: usize>(a: __m128i, b: __m128i) -> __m128i {
+    let p = const { &plan128(&byte_map(S::INDEX, LANES, 2, 16)) };
+    apply128(p, 1, b, apply128(p, 0, a, None)).unwrap_unchecked()
+}
+
+#[inline(always)]
+pub(crate) unsafe fn swizzle256<S: Swizzle, const LANES: usize>(a: __m256i) -> __m256i {
+    let p = const { &plan256(&byte_map(S::INDEX, LANES, 1, 32), 0) };
+    apply256(p, 0, a, LANES <= 8, None).unwrap_unchecked()
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shuffle256<S: Swizzle, const LANES: usize>(a: __m256i, b: __m256i) -> __m256i {
+    let p = const { &plan256(&byte_map(S::INDEX, LANES, 2, 32), 0) };
+    apply256(p, 1, b, LANES <= 8, apply256(p, 0, a, LANES <= 8, None)).unwrap_unchecked()
+}
+
+// One output half of a 512-bit shuffle, from the halves of `a` and `b`
+#[inline(always)]
+unsafe fn half512(p: &Plan256, a: [__m256i; 2], b: [__m256i; 2], dwords: bool) -> __m256i {
+    let r = apply256(p, 0, a[0], dwords, None);
+    let r = apply256(p, 1, a[1], dwords, r);
+    let r = apply256(p, 2, b[0], dwords, r);
+    apply256(p, 3, b[1], dwords, r).unwrap_unchecked()
+}
+
+#[inline(always)]
+pub(crate) unsafe fn swizzle512<S: Swizzle, const LANES: usize>(a: [__m256i; 2]) -> [__m256i; 2] {
+    let lo = const { &plan256(&byte_map(S::INDEX, LANES, 1, 64), 0) };
+    let hi = const { &plan256(&byte_map(S::INDEX, LANES, 1, 64), 32) };
+    [half512(lo, a, a, LANES <= 16), half512(hi, a, a, LANES <= 16)]
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shuffle512<S: Swizzle, const LANES: usize>(a: [__m256i; 2], b: [__m256i; 2]) -> [__m256i; 2] {
+    let lo = const { &plan256(&byte_map(S::INDEX, LANES, 2, 64), 0) };
+    let hi = const { &plan256(&byte_map(S::INDEX, LANES, 2, 64), 32) };
+    [half512(lo, a, b, LANES <= 16), half512(hi, a, b, LANES <= 16)]
+}
+
+// Index patterns of the lane helpers, for `L` lanes
+macro_rules! lane_pattern {
+    ($($name:ident<$($n:ident),*>: |$i:ident| $e:expr;)*) => {$(
+        pub(crate) struct $name<$(const $n: usize),*>;
+
+        impl<$(const $n: usize),*> Swizzle for $name<$($n),*> {
+            const INDEX: &'static [usize] = &{
+                let mut a = [0; L];
+                let mut $i = 0;
+
+                while $i < L {
+                    a[$i] = $e;
+                    $i += 1;
+                }
+
+                a
+            };
+        }
+    )*};
+}
+
+lane_pattern!(
+    Reverse<L>: |i| L - 1 - i;
+    RotateLeft<L, N>: |i| (i + N % L) % L;
+    RotateRight<L, N>: |i| (i + L - N % L) % L;
+    Broadcast<L, I>: |i| I;
+    InterleaveLo<L>: |i| i / 2 + i % 2 * L;
+    InterleaveHi<L>: |i| L / 2 + i / 2 + i % 2 * L;
+    DeinterleaveEven<L>: |i| 2 * i;
+    DeinterleaveOdd<L>: |i| 2 * i + 1;
+);
+
+// Bytes `bytes..` of `a` followed by the first bytes of `b`, with `permute2x128` for the 128-bit halves straddling
+// `a` and `b` and `alignr` within the halves. `bytes` has to be a constant to pick the immediate.
+#[inline(always)]
+unsafe fn alignr256(a: __m256i, b: __m256i, bytes: usize) -> __m256i {
+    let mid = _mm256_permute2x128_si256::<0x21>(a, b);
+
+    macro_rules! alignr {
+        ($($lo:literal $hi:literal)*) => {
+            match bytes {
+                0 => a,
+                16 => mid,
+                32 => b,
+                $($lo => _mm256_alignr_epi8::<$lo>(mid, a), $hi => _mm256_alignr_epi8::<$lo>(b, mid),)*
+                _ => unreachable!(),
+            }
+        };
+    }
+
+    alignr!(1 17 2 18 3 19 4 20 5 21 6 22 7 23 8 24 9 25 10 26 11 27 12 28 13 29 14 30 15 31)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shift_left256<const L: usize, const N: usize>(a: __m256i, fill: __m256i) -> __m256i {
+    const { assert!(N <= L, "Lane shift out of range!") };
+    alignr256(a, fill, N * (32 / L))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shift_right256<const L: usize, const N: usize>(a: __m256i, fill: __m256i) -> __m256i {
+    const { assert!(N <= L, "Lane shift out of range!") };
+    alignr256(fill, a, (L - N) * (32 / L))
+}
+
+// Bytes `bytes..` of `a` followed by the first bytes of `b`, `bytes` has to be a constant to pick the immediate
+#[inline(always)]
+unsafe fn alignr128(a: __m128i, b: __m128i, bytes: usize) -> __m128i {
+    macro_rules! alignr {
+        ($($n:literal)*) => {
+            match bytes {
+                $($n => _mm_alignr_epi8::<$n>(b, a),)*
+                _ => unreachable!(),
+            }
+        };
+    }
+
+    alignr!(0 1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16)
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shift_left128<const L: usize, const N: usize>(a: __m128i, fill: __m128i) -> __m128i {
+    const { assert!(N <= L, "Lane shift out of range!") };
+    alignr128(a, fill, N * (16 / L))
+}
+
+#[inline(always)]
+pub(crate) unsafe fn shift_right128<const L: usize, const N: usize>(a: __m128i, fill: __m128i) -> __m128i {
+    const { assert!(N <= L, "Lane shift out of range!") };
+    alignr128(fill, a, (L - N) * (16 / L))
+}
@@ -0,0 +1,200 @@
+//! Traits over the vector types, for kernels generic over the lane type and width. Each method forwards to the
+//! inherent method of the same name.
+
+use crate::{
+    f32x16, f32x4, f32x8, f64x2, f64x4, f64x8, i16x16, i16x8, i32x16, i32x4, i32x8, i64x2, i64x4, i64x8, i8x32, m16x16,
+    m16x8, m32x16, m32x4, m32x8, m64x2, m64x4, m64x8, m8x32, u16x16, u16x8, u32x4, u32x8, u64x2, u64x4, u8x32,
+};
+use std::{
+    fmt::Debug,
+    ops::{
+        Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign, Mul, MulAssign,
+        Neg, Not, Shl, ShlAssign, Shr, ShrAssign, Sub, SubAssign,
+    },
+};
+
+/// Operations shared by all vector types.
+pub trait SimdVector:
+    Copy + Debug + Default + PartialEq + Add<Output = Self> + AddAssign + Sub<Output = Self> + SubAssign
+{
+    type Scalar: Copy + Debug + PartialOrd;
+    /// Result of the lane wise comparisons.
+    type Mask: Copy + BitAnd<Output = Self::Mask> + BitOr<Output = Self::Mask> + Not<Output = Self::Mask>;
+    const LANES: usize;
+
+    fn splat(v: Self::Scalar) -> Self;
+
+    /// Loads the first [`LANES`](Self::LANES) elements of `a`, panics if it's shorter.
+    fn from_slice(a: &[Self::Scalar]) -> Self;
+
+    /// Loads up to [`LANES`](Self::LANES) elements of `a`, zeroing the missing lanes.
+    fn load_partial(a: &[Self::Scalar]) -> Self;
+
+    /// Stores to the first [`LANES`](Self::LANES) elements of `a`, panics if it's shorter.
+    fn store(self, a: &mut [Self::Scalar]);
+
+    /// Stores up to [`LANES`](Self::LANES) lanes to `a`.
+    fn store_partial(self, a: &mut [Self::Scalar]);
+
+    fn min(self, other: Self) -> Self;
+    fn max(self, other: Self) -> Self;
+    fn eq(self, other: Self) -> Self::Mask;
+    fn ne(self, other: Self) -> Self::Mask;
+    fn lt(self, other: Self) -> Self::Mask;
+    fn le(self, other: Self) -> Self::Mask;
+    fn gt(self, other: Self) -> Self::Mask;
+    fn ge(self, other: Self) -> Self::Mask;
+
+    /// Lanes of `a` where `mask` is set, otherwise of `b`.
+    fn select(mask: Self::Mask, a: Self, b: Self) -> Self;
+}
+
+/// Operations of the `f32` and `f64` vectors.
+pub trait SimdFloat:
+    SimdVector + Mul<Output = Self> + MulAssign + Div<Output = Self> + DivAssign + Neg<Output = Self>
+{
+    /// `self * mul + add` with a single rounding.
+    fn mul_add(self, mul: Self, add: Self) -> Self;
+
+    fn sqrt(self) -> Self;
+    fn abs(self) -> Self;
+    fn floor(self) -> Self;
+    fn ceil(self) -> Self;
+    fn is_nan(self) -> Self::Mask;
+    fn sum(self) -> Self::Scalar;
+
+    /// Minimum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    fn reduce_min(self) -> Self::Scalar;
+
+    /// Maximum of all lanes, ignoring NaN lanes unless all lanes are NaN.
+    fn reduce_max(self) -> Self::Scalar;
+}
+
+/// Operations of the signed and unsigned integer vectors. `>>` is arithmetic for signed and logical for unsigned
+/// lanes.
+pub trait SimdInt:
+    SimdVector
+    + BitAnd<Output = Self>
+    + BitAndAssign
+    + BitOr<Output = Self>
+    + BitOrAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
+    + Not<Output = Self>
+    + Shl<i32, Output = Self>
+    + ShlAssign<i32>
+    + Shr<i32, Output = Self>
+    + ShrAssign<i32>
+{
+    /// `self & !other`.
+    fn andnot(self, other: Self) -> Self;
+}
+
+macro_rules! impl_vector {
+    ($($t:ident: $s:ty, $m:ty, $n:expr);*) => {$(
+        impl SimdVector for $t {
+            type Scalar = $s;
+            type Mask = $m;
+            const LANES: usize = $n;
+
+            #[inline(always)]
+            fn splat(v: $s) -> Self { $t::splat(v) }
+
+            #[inline(always)]
+            fn from_slice(a: &[$s]) -> Self { $t::from_slice(a) }
+
+            #[inline(always)]
+            fn load_partial(a: &[$s]) -> Self { $t::load_partial(a) }
+
+            #[inline(always)]
+            fn store(self, a: &mut [$s]) { $t::store(self, a) }
+
+            #[inline(always)]
+            fn store_partial(self, a: &mut [$s]) { $t::store_partial(self, a) }
+
+            #[inline(always)]
+            fn min(self, other: Self) -> Self { $t::min(self, other) }
+
+            #[inline(always)]
+            fn max(self, other: Self) -> Self { $t::max(self, other) }
+
+            #[inline(always)]
+            fn eq(self, other: Self) -> $m { $t::eq(self, other) }
+
+            #[inline(always)]
+            fn ne(self, other: Self) -> $m { $t::ne(self, other) }
+
+            #[inline(always)]
+            fn lt(self, other: Self) -> $m { $t::lt(self, other) }
+
+            #[inline(always)]
+            fn le(self, other: Self) -> $m { $t::le(self, other) }
+
+            #[inline(always)]
+            fn gt(self, other: Self) -> $m { $t::gt(self, other) }
+
+            #[inline(always)]
+            fn ge(self, other: Self) -> $m { $t::ge(self, other) }
+
+            #[inline(always)]
+            fn select(mask: $m, a: Self, b: Self) -> Self { $t::select(mask, a, b) }
+        }
+    )*};
+}
+
+macro_rules! impl_float {
+    ($($t:ident),*) => {$(
+        impl SimdFloat for $t {
+            #[inline(always)]
+            fn mul_add(self, mul: Self, add: Self) -> Self { $t::mul_add(self, mul, add) }
+
+            #[inline(always)]
+            fn sqrt(self) -> Self { $t::sqrt(self) }
+
+            #[inline(always)]
+            fn abs(self) -> Self { $t::abs(self) }
+
+            #[inline(always)]
+            fn floor(self) -> Self { $t::floor(self) }
+
+            #[inline(always)]
+            fn ceil(self) -> Self { $t::ceil(self) }
+
+            #[inline(always)]
+            fn is_nan(self) -> Self::Mask { $t::is_nan(self) }
+
+            #[inline(always)]
+            fn sum(self) -> Self::Scalar { $t::sum(self) }
+
+            #[inline(always)]
+            fn reduce_min(self) -> Self::Scalar { $t::reduce_min(self) }
+
+            #[inline(always)]
+            fn reduce_max(self) -> Self::Scalar { $t::reduce_max(self) }
+        }
+    )*};
+}
+
+macro_rules! impl_int {
+    ($($t:ident),*) => {$(
+        impl SimdInt for $t {
+            #[inline(always)]
+            fn andnot(self, other: Self) -> Self { $t::andnot(self, other) }
+        }
+    )*};
+}
+
+impl_vector!(
+    f32x4: f32, m32x4, 4; f32x8: f32, m32x8, 8; f32x16: f32, m32x16, 16;
+    f64x2: f64, m64x2, 2; f64x4: f64, m64x4, 4; f64x8: f64, m64x8, 8;
+    i8x32: i8, m8x32, 32; u8x32: u8, m8x32, 32;
+    i16x8: i16, m16x8, 8; i16x16: i16, m16x16, 16; u16x8: u16, m16x8, 8; u16x16: u16, m16x16, 16;
+    i32x4: i32, m32x4, 4; i32x8: i32, m32x8, 8; i32x16: i32, m32x16, 16; u32x4: u32, m32x4, 4; u32x8: u32, m32x8, 8;
+    i64x2: i64, m64x2, 2; i64x4: i64, m64x4, 4; i64x8: i64, m64x8, 8; u64x2: u64, m64x2, 2; u64x4: u64, m64x4, 4
+);
+
+impl_float!(f32x4, f32x8, f32x16, f64x2, f64x4, f64x8);
+
+impl_int!(
+    i8x32, u8x32, i16x8, i16x16, u16x8, u16x16, i32x4, i32x8, i32x16, u32x4, u32x8, i64x2, i64x4, i64x8, u64x2, u64x4
+);